@@ -2,7 +2,9 @@ use dioxus::logger::tracing::error;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use thingspace_sdk::models::{AccountDeviceListResponse, Device, Error, NiddMessage, NiddRequest};
+use thingspace_sdk::models::{
+  AccountDeviceListResponse, Device, DeviceIdKind, Error, NiddMessage, NiddRequest,
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestCredentials, RequestInit, RequestMode, Response};
@@ -76,7 +78,7 @@ pub async fn device_list() {
       let mut dev_map = HashMap::<String, Device>::new();
       for dev in resp.devices {
         for did in dev.device_ids.iter() {
-          if did.kind == "imei" {
+          if did.kind == DeviceIdKind::Imei {
             dev_map.insert(did.id.clone(), dev.to_owned());
             break;
           }
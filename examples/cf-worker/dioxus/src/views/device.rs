@@ -1,6 +1,6 @@
 use crate::api::send_nidd;
 use dioxus::prelude::*;
-use thingspace_sdk::models::{DeviceID, NiddMessage};
+use thingspace_sdk::models::{DeviceID, DeviceIdKind, NiddMessage, format_timestamp};
 
 #[component]
 pub fn DeviceView(id: String) -> Element {
@@ -45,7 +45,7 @@ fn DeviceInfo(id: String) -> Element {
                 tr {
                   for did in dev.device_ids.iter() {
                     th { class: "border-r border-base-content/5",
-                      "{did.kind.to_uppercase()}"
+                      "{did.kind.to_string().to_uppercase()}"
                     }
                   }
                 }
@@ -84,14 +84,14 @@ fn DeviceInfo(id: String) -> Element {
                 tr {
                   // td { class: "border-r border-base-content/5", "{dev.connected}" }
                   td { class: "border-r border-base-content/5",
-                    "{dev.last_connection_date}"
+                    "{dev.last_connection_date.as_ref().map(format_timestamp).unwrap_or_default()}"
                   }
-                  td { class: "border-r border-base-content/5", "{dev.created_at}" }
+                  td { class: "border-r border-base-content/5", "{dev.created_at.as_ref().map(format_timestamp).unwrap_or_default()}" }
                   td { class: "border-r border-base-content/5",
                     "{dev.last_activation_by}"
                   }
                   td { class: "border-r border-base-content/5",
-                    "{dev.last_activation_date}"
+                    "{dev.last_activation_date.as_ref().map(format_timestamp).unwrap_or_default()}"
                   }
                 }
               }
@@ -113,7 +113,9 @@ fn DeviceInfo(id: String) -> Element {
                 tr {
                   td { class: "border-r border-base-content/5", "{dev.account_name}" }
                   td { class: "border-r border-base-content/5",
-                    "{dev.group_names[0]}"
+                    for group_name in dev.group_names.iter() {
+                      p { "{group_name}" }
+                    }
                   }
                 }
               }
@@ -148,7 +150,7 @@ fn DeviceInfo(id: String) -> Element {
                       "{cinf.state}"
                     }
                     td { class: "border-r border-base-content/5",
-                      "{dev.billing_cycle_end_date}"
+                      "{dev.billing_cycle_end_date.as_ref().map(format_timestamp).unwrap_or_default()}"
                     }
                   }
                 }
@@ -206,7 +208,7 @@ fn SendNiddModal(id: String) -> Element {
                   }
                   msg.device_ids = vec![
                       DeviceID {
-                          kind: "imei".to_string(),
+                          kind: DeviceIdKind::Imei,
                           id: imei,
                       },
                   ];
@@ -1,5 +1,6 @@
 use crate::Route;
 use dioxus::prelude::*;
+use thingspace_sdk::models::{DeviceIdKind, format_timestamp};
 
 #[component]
 pub fn DevicesView() -> Element {
@@ -37,13 +38,13 @@ fn DevicesTable() -> Element {
                 }
                 td {
                   for did in device.device_ids.iter() {
-                    if did.kind == "iccId" {
+                    if did.kind == DeviceIdKind::Iccid {
                       "{did.id}"
                     }
                   }
                 }
                 td { "{device.connected}" }
-                td { "{device.last_connection_date}" }
+                td { "{device.last_connection_date.as_ref().map(format_timestamp).unwrap_or_default()}" }
               }
             }
           }
@@ -1,7 +1,7 @@
 use crate::cache;
-use thingspace_sdk::api::devices_list;
+use thingspace_sdk::api::{Endpoints, devices_list, error_response};
 use thingspace_sdk::models::AccountDeviceListRequest;
-use worker::{Request, Response, RouteContext, console_error};
+use worker::{Request, Response, RouteContext};
 
 pub async fn list_devices(_req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
   let atoken = cache::access_token(&ctx).await?;
@@ -20,13 +20,10 @@ pub async fn list_devices(_req: Request, ctx: RouteContext<()>) -> worker::Resul
     largest_device_id_seen: None,
   };
 
-  let vz_req = devices_list(&atoken, &stoken, &adl).await;
+  let vz_req = devices_list(&Endpoints::default(), &atoken, &stoken, &adl).await;
 
   match vz_req {
-    Ok(resp) => Ok(resp),
-    Err(e) => {
-      console_error!("{e}");
-      Response::error(e.to_string(), 500)
-    }
+    Ok(devices) => Response::from_json(&devices),
+    Err(e) => error_response(&e),
   }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use worker::{Request, Response, RouteContext, Secret, console_error};
+use worker::{Request, Response, RouteContext, Secret};
 
-use thingspace_sdk::api::{get_access_token, get_session_token};
+use thingspace_sdk::api::{Endpoints, error_response, get_access_token, get_session_token};
 use thingspace_sdk::models::SessionRequestBody;
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -13,14 +13,16 @@ pub async fn access_token(_req: Request, ctx: RouteContext<()>) -> worker::Resul
   let public_key = ctx.var("PUBLIC_KEY")?;
   let private_key = ctx.var("PRIVATE_KEY")?;
 
-  let vz_req = get_access_token(&public_key.to_string(), &private_key.to_string()).await;
+  let vz_req = get_access_token(
+    &Endpoints::default(),
+    &public_key.to_string(),
+    &private_key.to_string(),
+  )
+  .await;
 
   match vz_req {
-    Ok(resp) => Ok(resp),
-    Err(e) => {
-      console_error!("{:?}", e);
-      Response::error(e.to_string(), 500)
-    }
+    Ok(resp) => Response::from_json(&resp),
+    Err(e) => error_response(&e),
   }
 }
 
@@ -52,14 +54,11 @@ pub async fn session_token(mut req: Request, ctx: RouteContext<()>) -> worker::R
       return Response::error("Request missing 'access_token'", 400);
     }
 
-    let vz_req = get_session_token(&cred, &token.access_token).await;
+    let vz_req = get_session_token(&Endpoints::default(), &cred, &token.access_token).await;
 
     match vz_req {
-      Ok(resp) => Ok(resp),
-      Err(e) => {
-        console_error!("{:?}", e);
-        Response::error(e.to_string(), 500)
-      }
+      Ok(resp) => Response::from_json(&resp),
+      Err(e) => error_response(&e),
     }
   } else {
     Response::error("Wrong 'Content-Type' header", 400)
@@ -1,7 +1,7 @@
 use crate::cache;
-use thingspace_sdk::api::send_nidd;
+use thingspace_sdk::api::{Endpoints, error_response, send_nidd};
 use thingspace_sdk::models::NiddMessage;
-use worker::{Request, Response, RouteContext, console_error};
+use worker::{Request, Response, RouteContext};
 
 pub async fn send_nidd_msg(mut req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
   let ctype = req.headers().get("Content-Type");
@@ -24,14 +24,11 @@ pub async fn send_nidd_msg(mut req: Request, ctx: RouteContext<()>) -> worker::R
 
     msg.account_name = aname.to_string();
 
-    let vz_req = send_nidd(&atoken, &stoken, &mut msg).await;
+    let vz_req = send_nidd(&Endpoints::default(), &atoken, &stoken, &msg).await;
 
     match vz_req {
-      Ok(resp) => Ok(resp),
-      Err(e) => {
-        console_error!("{e}");
-        Response::error(e.to_string(), 500)
-      }
+      Ok(request) => Response::from_json(&request),
+      Err(e) => error_response(&e),
     }
   } else {
     Response::error("Wrong 'Content-Type' header", 400)
@@ -1,23 +1,22 @@
 use crate::cache;
 use thingspace_sdk::api::{
-  deregister_callback_listener, list_callback_listeners, register_callback_listener,
+  Endpoints, deregister_callback_listener, error_response, list_callback_listeners,
+  register_callback_listener,
 };
 use thingspace_sdk::models::CallbackListener;
-use worker::{Request, Response, RouteContext, console_error};
+use worker::{Request, Response, RouteContext};
 
 pub async fn list_listeners(_req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
   let aname = ctx.var("ACCOUNT_NAME")?;
   let atoken = cache::access_token(&ctx).await?;
   let stoken = cache::session_token(&ctx).await?;
 
-  let vz_req = list_callback_listeners(&aname.to_string(), &atoken, &stoken).await;
+  let vz_req =
+    list_callback_listeners(&Endpoints::default(), &aname.to_string(), &atoken, &stoken).await;
 
   match vz_req {
-    Ok(resp) => Ok(resp),
-    Err(e) => {
-      console_error!("{:?}", e);
-      Response::error(e.to_string(), 500)
-    }
+    Ok(resp) => Response::from_json(&resp),
+    Err(e) => error_response(&e),
   }
 }
 
@@ -48,14 +47,18 @@ pub async fn create_listeners(mut req: Request, ctx: RouteContext<()>) -> worker
     let atoken = cache::access_token(&ctx).await?;
     let stoken = cache::session_token(&ctx).await?;
 
-    let vz_req = register_callback_listener(&aname.to_string(), &atoken, &stoken, &cbl).await;
+    let vz_req = register_callback_listener(
+      &Endpoints::default(),
+      &aname.to_string(),
+      &atoken,
+      &stoken,
+      &cbl,
+    )
+    .await;
 
     match vz_req {
-      Ok(resp) => Ok(resp),
-      Err(e) => {
-        console_error!("{:?}", e);
-        Response::error(e.to_string(), 500)
-      }
+      Ok(resp) => Response::from_json(&resp),
+      Err(e) => error_response(&e),
     }
   } else {
     Response::error("Wrong 'Content-Type' header", 400)
@@ -68,14 +71,18 @@ pub async fn delete_listeners(_req: Request, ctx: RouteContext<()>) -> worker::R
     let atoken = cache::access_token(&ctx).await?;
     let stoken = cache::session_token(&ctx).await?;
 
-    let vz_req = deregister_callback_listener(&aname.to_string(), &atoken, &stoken, sname).await;
+    let vz_req = deregister_callback_listener(
+      &Endpoints::default(),
+      &aname.to_string(),
+      &atoken,
+      &stoken,
+      sname,
+    )
+    .await;
 
     match vz_req {
-      Ok(resp) => Ok(resp),
-      Err(e) => {
-        console_error!("{:?}", e);
-        Response::error(e.to_string(), 500)
-      }
+      Ok(resp) => Response::from_json(&resp),
+      Err(e) => error_response(&e),
     }
   } else {
     Response::error("Missing 'service_name' parameter in url", 400)
@@ -1,7 +1,7 @@
 use worker::{RouteContext, Secret, console_debug, console_error};
 
-use thingspace_sdk::api::{get_access_token, get_session_token};
-use thingspace_sdk::models::{LoginResponse, Session, SessionRequestBody};
+use thingspace_sdk::api::{Endpoints, get_access_token, get_session_token};
+use thingspace_sdk::models::SessionRequestBody;
 
 pub async fn access_token(ctx: &RouteContext<()>) -> worker::Result<String> {
   let kv = ctx.kv("THINGSPACE")?;
@@ -13,27 +13,25 @@ pub async fn access_token(ctx: &RouteContext<()>) -> worker::Result<String> {
       let public_key: Secret = ctx.var("PUBLIC_KEY")?;
       let private_key = ctx.var("PRIVATE_KEY")?;
 
-      let vz_req: Result<worker::Response, thingspace_sdk::models::Error> =
-        get_access_token(&public_key.to_string(), &private_key.to_string()).await;
+      let vz_req = get_access_token(
+        &Endpoints::default(),
+        &public_key.to_string(),
+        &private_key.to_string(),
+      )
+      .await;
 
       match vz_req {
-        Ok(mut resp) => match resp.json::<LoginResponse>().await {
-          Ok(login) => {
-            console_debug!("{login}");
+        Ok(login) => {
+          console_debug!("{login}");
 
-            kv.put("access_token", &login.access_token)?
-              .expiration_ttl(login.expires_in.try_into().unwrap())
-              .metadata(&login.scope)?
-              .execute()
-              .await?;
+          kv.put("access_token", &login.access_token)?
+            .expiration_ttl(login.expires_in.try_into().unwrap())
+            .metadata(&login.scope)?
+            .execute()
+            .await?;
 
-            Ok(login.access_token)
-          }
-          Err(e) => {
-            console_error!("{e}");
-            Err(e)
-          }
-        },
+          Ok(login.access_token)
+        }
         Err(e) => {
           console_error!("{:?}", e);
           Err(worker::Error::RustError(e.to_string()))
@@ -65,25 +63,19 @@ pub async fn session_token(ctx: &RouteContext<()>) -> worker::Result<String> {
             password: password.to_string(),
           };
 
-          let vz_req = get_session_token(&cred, &access_token).await;
+          let vz_req = get_session_token(&Endpoints::default(), &cred, &access_token).await;
 
           match vz_req {
-            Ok(mut resp) => match resp.json::<Session>().await {
-              Ok(login) => {
-                console_debug!("{login}");
+            Ok(login) => {
+              console_debug!("{login}");
 
-                kv.put("session_token", &login.session_token)?
-                  .expiration_ttl(login.expires_in.try_into().unwrap())
-                  .execute()
-                  .await?;
+              kv.put("session_token", &login.session_token)?
+                .expiration_ttl(login.expires_in.try_into().unwrap())
+                .execute()
+                .await?;
 
-                Ok(login.session_token)
-              }
-              Err(e) => {
-                console_error!("{e}");
-                Err(e)
-              }
-            },
+              Ok(login.session_token)
+            }
             Err(e) => {
               console_error!("{:?}", e);
               Err(worker::Error::RustError(e.to_string()))
@@ -1,51 +1,28 @@
-use serde::{Deserialize, Serialize};
 use std::fs;
-use thingspace_sdk::api::{
-  deregister_callback_listener, devices_list, list_callback_listeners, register_callback_listener,
-  send_nidd,
-};
+use thingspace_sdk::api::{ReqwestTransport, ThingSpaceClient};
 use thingspace_sdk::models::{
-  AccountDeviceListRequest, CallbackListener, Device, DeviceID, Error, NiddMessage,
-  SessionRequestBody,
+  AccountDeviceListRequest, CallbackListener, Device, DeviceID, Error, NiddMessage, Secrets,
 };
 
-/// A struct containing a user's Verizon account secrets required for API use.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-#[allow(dead_code)]
-pub struct Secrets {
-  pub public_key: String,
-  pub private_key: String,
-  pub username: String,
-  pub password: String,
-  pub account_name: String,
-}
-
 fn read_secrets_from_file() -> Result<Secrets, Box<dyn std::error::Error>> {
   let file = fs::read_to_string("./secrets.toml")?;
   let secrets = toml::from_str::<Secrets>(&file)?;
   Ok(secrets)
 }
 
-struct Credentials {
-  access_token: String,
-  session_token: String,
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
   let secrets = read_secrets_from_file().expect("Failed to read from secrets.toml");
 
-  let mut credentials = Credentials {
-    access_token: String::with_capacity(64),
-    session_token: String::with_capacity(64),
-  };
-
-  let client = reqwest::Client::new();
+  let client = ThingSpaceClient::new(secrets, None).await?;
+  let tokens = client.tokens().await?;
+  println!(
+    "Access token: {}\nSession token: {}",
+    tokens.access_token, tokens.session_token
+  );
 
-  get_credentials(&secrets, &mut credentials, client.clone()).await;
-  let dev_resp = get_devices(&secrets, &mut credentials, client.clone()).await;
+  let dev_resp = get_devices(&client).await;
 
-  // let mut dev_list: Vec<Device> = Vec::with_capacity(1);
   match dev_resp {
     Ok(dev_list) => {
       let mut dev_ids: Vec<DeviceID> = Vec::with_capacity(dev_list.len());
@@ -58,75 +35,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
       }
 
-      send_nidd_msgs(&secrets.account_name, &credentials, dev_ids, client).await;
+      send_nidd_msgs(&client, dev_ids).await;
     }
     Err(e) => {
       println!("{e:?}");
     }
   }
 
-  // set_callback_listener(&secrets.account_name, &mut credentials, client.clone()).await;
-  // print_listeners(&secrets.account_name, &mut credentials, client.clone()).await;
-  // delete_callback_listener(&secrets.account_name, &mut credentials, client.clone()).await;
-  // print_listeners(&secrets.account_name, &mut credentials, client).await;
-  Ok(())
-}
-
-async fn get_credentials(secrets: &Secrets, cred: &mut Credentials, client: reqwest::Client) {
-  match thingspace_sdk::api::get_access_token(
-    &secrets.public_key,
-    &secrets.private_key,
-    Some(client.clone()),
-  )
-  .await
-  {
-    Ok(response) => {
-      println!(
-        "Access token: {}, Scope: {}, TokenType: {}, Expires in: {}",
-        response.access_token, response.scope, response.token_type, response.expires_in
-      );
-      cred.access_token.clone_from(&response.access_token);
-    }
-    Err(error) => {
-      println!("{error:?}");
-    }
+  if std::env::args().any(|arg| arg == "--callbacks") {
+    set_callback_listener(&client).await;
+    print_listeners(&client).await;
+    delete_callback_listener(&client).await;
+    print_listeners(&client).await;
   }
 
-  let user_info = SessionRequestBody {
-    username: secrets.username.clone(),
-    password: secrets.password.clone(),
-  };
-
-  match thingspace_sdk::api::get_session_token(&user_info, &cred.access_token, Some(client)).await {
-    Ok(response) => {
-      println!(
-        "Session token: {}, Expires in: {}",
-        response.session_token, response.expires_in
-      );
-      cred.session_token.clone_from(&response.session_token);
-    }
-    Err(error) => {
-      println!("{error:?}");
-    }
-  }
+  Ok(())
 }
 
-async fn get_devices(
-  secrets: &Secrets,
-  cred: &mut Credentials,
-  client: reqwest::Client,
-) -> Result<Vec<Device>, Error> {
+async fn get_devices(client: &ThingSpaceClient<ReqwestTransport>) -> Result<Vec<Device>, Error> {
   let mut device_request = AccountDeviceListRequest::default();
 
-  match devices_list(
-    &secrets.account_name,
-    &cred.access_token,
-    &cred.session_token,
-    &mut device_request,
-    Some(client),
-  )
-  .await
-  {
+  match client.devices_list(&mut device_request).await {
     Ok(response) => {
       println!("{:#?}", response.devices[0]);
       Ok(response.devices)
@@ -135,29 +64,17 @@ async fn get_devices(
   }
 }
 
-async fn send_nidd_msgs(
-  aname: &str,
-  cred: &Credentials,
-  dev_ids: Vec<DeviceID>,
-  client: reqwest::Client,
-) {
+async fn send_nidd_msgs(client: &ThingSpaceClient<ReqwestTransport>, dev_ids: Vec<DeviceID>) {
   let mut msg = NiddMessage {
-    account_name: aname.to_string(),
     device_ids: dev_ids,
     maximum_delivery_time: 30,
     message: "SEVMTE8=".to_string(),
+    ..Default::default()
   };
 
-  match send_nidd(
-    &cred.access_token,
-    &cred.session_token,
-    &mut msg,
-    Some(client),
-  )
-  .await
-  {
-    Ok(response) => {
-      println!("{response:?}",);
+  match client.send_nidd(&mut msg).await {
+    Ok(handle) => {
+      println!("NIDD request id: {}", handle.request_id());
     }
     Err(error) => {
       println!("{error:?}");
@@ -165,22 +82,14 @@ async fn send_nidd_msgs(
   }
 }
 
-async fn set_callback_listener(aname: &str, cred: &mut Credentials, client: reqwest::Client) {
+async fn set_callback_listener(client: &ThingSpaceClient<ReqwestTransport>) {
   let rcl = CallbackListener {
     service_name: "CarrierService".to_string(),
     url: "https://mock.thingspace.verizon.com/webhook".to_string(),
     ..Default::default()
   };
 
-  match register_callback_listener(
-    aname,
-    &cred.access_token,
-    &cred.session_token,
-    &rcl,
-    Some(client),
-  )
-  .await
-  {
+  match client.register_callback_listener(&rcl).await {
     Ok(response) => {
       println!(
         "Account: {}\nService: {}",
@@ -193,18 +102,8 @@ async fn set_callback_listener(aname: &str, cred: &mut Credentials, client: reqw
   }
 }
 
-async fn delete_callback_listener(aname: &str, cred: &mut Credentials, client: reqwest::Client) {
-  let service_name = "CarrierService".to_string();
-
-  match deregister_callback_listener(
-    aname,
-    &cred.access_token,
-    &cred.session_token,
-    &service_name,
-    Some(client),
-  )
-  .await
-  {
+async fn delete_callback_listener(client: &ThingSpaceClient<ReqwestTransport>) {
+  match client.deregister_callback_listener("CarrierService").await {
     Ok(response) => {
       println!(
         "Account: {}\nService: {}",
@@ -217,9 +116,8 @@ async fn delete_callback_listener(aname: &str, cred: &mut Credentials, client: r
   }
 }
 
-async fn print_listeners(aname: &str, cred: &mut Credentials, client: reqwest::Client) {
-  match list_callback_listeners(aname, &cred.access_token, &cred.session_token, Some(client)).await
-  {
+async fn print_listeners(client: &ThingSpaceClient<ReqwestTransport>) {
+  match client.list_callback_listeners().await {
     Ok(rcls) => {
       for rcl in rcls {
         println!(
@@ -0,0 +1,1311 @@
+#[cfg(feature = "reqwest")]
+use crate::api::ReqwestTransport;
+use crate::api::operations::{
+  account_information, account_states, activate_devices, aggregate_usage, change_service_plan,
+  configure_nidd, connection_history, create_device_group, deactivate_devices, delete_device_group,
+  delete_devices, deregister_callback_listener, device_group, device_usage, devices_list,
+  get_access_token, get_session_token, go_to_state, list_callback_listeners, list_device_groups,
+  nidd_configuration, provisioning_history, reachability_status, register_callback_listener,
+  request_status, restore_devices, send_nidd, send_sms, service_plans, sms_history,
+  start_sms_callbacks, subscribe_reachability, suspend_devices, unsubscribe_reachability,
+  update_custom_fields, update_device_group,
+};
+use crate::api::{
+  AuthTokens, ConnectionHistorySource, ConnectionHistoryStream, DeviceGroupSource,
+  DeviceGroupStream, DeviceListSource, DeviceStream, DeviceUsageSource, DeviceUsageStream,
+  Endpoints, HttpTransport, Paginated, ProvisioningHistorySource, ProvisioningHistoryStream,
+  RateLimitTransport, RateLimiter, RequestHandle, RetryPolicy, RetryTransport, TokenManager,
+};
+use crate::models::{
+  AccountDeviceListRequest, AccountDeviceListResponse, AccountInformation, AccountStatesResponse,
+  ActivateDevicesRequest, AggregateUsageRequest, AggregateUsageResponse, CallbackListener,
+  CallbackListenerResponse, ChangeServicePlanRequest, ConnectionHistoryRequest,
+  ConnectionHistoryResponse, CreateDeviceGroupRequest, CustomField, CustomFieldKey,
+  DeactivateDevicesRequest, DeleteDevicesRequest, Device, DeviceGroup, DeviceGroupInformation,
+  DeviceGroupPageRequest, DeviceID, DeviceIdList, DeviceStateRequest, DeviceUsageRequest,
+  DeviceUsageResponse, Error, GoToStateRequest, NiddConfiguration, NiddConfigurationListRequest,
+  NiddConfigurationRequest, NiddMessage, ProvisioningHistoryRequest, ProvisioningHistoryResponse,
+  ReachabilityStatusRequest, ReachabilitySubscriptionRequest, RequestStatusResponse, Secrets,
+  SendSmsRequest, ServicePlan, SessionRequestBody, SmsHistoryResponse, SuccessResponse,
+  UpdateCustomFieldsRequest, UpdateDeviceGroupRequest,
+};
+use std::future::Future;
+use std::sync::Arc;
+
+/// A ThingSpace API client that owns the account [`Secrets`], the OAuth2 access token, the M2M
+/// session token and a single [`HttpTransport`] that is reused for every request.
+///
+/// Tokens are kept in a [`TokenManager`] and refreshed before they expire. Clones of a client
+/// share the same tokens. If ThingSpace still rejects a token (see [`Error::is_auth_error`]),
+/// the client logs in again and replays the request once. Transient failures are retried
+/// according to the client's [`RetryPolicy`], see [`ThingSpaceClient::with_retry_policy`], and
+/// every attempt waits on the client's [`RateLimiter`], see
+/// [`ThingSpaceClient::with_rate_limiter`].
+///
+/// With the `reqwest` feature, [`ThingSpaceClient::new`] creates a client backed by a
+/// [`ReqwestTransport`](crate::api::ReqwestTransport). Any other transport can be used with
+/// [`ThingSpaceClient::with_transport`].
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::{HttpTransport, ThingSpaceClient};
+/// use thingspace_sdk::models::AccountDeviceListRequest;
+///
+/// async fn print_devices<T: HttpTransport>(client: &ThingSpaceClient<T>) {
+///   let mut device_request = AccountDeviceListRequest::default();
+///
+///   match client.devices_list(&mut device_request).await {
+///     Ok(response) => {
+///       for device in response.devices {
+///         println!("{device:?}");
+///       }
+///     }
+///     Err(error) => {
+///       println!("{error:?}");
+///     }
+///   }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThingSpaceClient<T: HttpTransport> {
+  secrets: Secrets,
+  endpoints: Endpoints,
+  tokens: Arc<TokenManager>,
+  transport: RetryTransport<RateLimitTransport<T>>,
+}
+
+#[cfg(feature = "reqwest")]
+impl ThingSpaceClient<ReqwestTransport> {
+  /// Creates a new [`ThingSpaceClient`] for the default [`Endpoints`] and requests both an
+  /// OAuth2 access token and a M2M session token with the given [`Secrets`].
+  /// If `client` is `None` a new [`reqwest::Client`] is created.
+  /// # Panics
+  /// See [`get_access_token`](crate::api::get_access_token).
+  /// # Errors
+  /// Returns the first `Error` encountered while requesting the tokens.
+  pub async fn new(
+    secrets: Secrets,
+    client: Option<reqwest::Client>,
+  ) -> Result<ThingSpaceClient<ReqwestTransport>, Error> {
+    ThingSpaceClient::with_endpoints(secrets, Endpoints::default(), client).await
+  }
+
+  /// Creates a new [`ThingSpaceClient`] that sends every request to the given [`Endpoints`]
+  /// and requests both an OAuth2 access token and a M2M session token with the given [`Secrets`].
+  /// If `client` is `None` a new [`reqwest::Client`] is created.
+  /// # Panics
+  /// See [`get_access_token`](crate::api::get_access_token).
+  /// # Errors
+  /// Returns the first `Error` encountered while requesting the tokens.
+  pub async fn with_endpoints(
+    secrets: Secrets,
+    endpoints: Endpoints,
+    client: Option<reqwest::Client>,
+  ) -> Result<ThingSpaceClient<ReqwestTransport>, Error> {
+    ThingSpaceClient::with_transport(secrets, endpoints, ReqwestTransport::new(client)).await
+  }
+}
+
+impl<T: HttpTransport> ThingSpaceClient<T> {
+  /// Creates a new [`ThingSpaceClient`] that sends every request to the given [`Endpoints`]
+  /// through `transport` with the default [`RetryPolicy`], and requests both an OAuth2 access
+  /// token and a M2M session token with the given [`Secrets`].
+  /// # Panics
+  /// See [`get_access_token`](crate::api::get_access_token).
+  /// # Errors
+  /// Returns the first `Error` encountered while requesting the tokens.
+  pub async fn with_transport(
+    secrets: Secrets,
+    endpoints: Endpoints,
+    transport: T,
+  ) -> Result<ThingSpaceClient<T>, Error> {
+    let transport = RateLimitTransport::new(
+      transport,
+      Arc::new(RateLimiter::default()),
+      &secrets.account_name,
+    );
+
+    let tsc = ThingSpaceClient {
+      secrets,
+      endpoints,
+      tokens: Arc::new(TokenManager::default()),
+      transport: RetryTransport::new(transport, RetryPolicy::default()),
+    };
+    tsc.tokens().await?;
+
+    Ok(tsc)
+  }
+
+  /// Returns the current [`AuthTokens`], requesting a new OAuth2 access token and/or M2M session
+  /// token first if they are missing or about to expire.
+  /// # Panics
+  /// See [`get_access_token`](crate::api::get_access_token).
+  /// # Errors
+  /// Returns the first `Error` encountered while requesting the tokens.
+  pub async fn tokens(&self) -> Result<AuthTokens, Error> {
+    let cred = SessionRequestBody {
+      username: self.secrets.username.clone(),
+      password: self.secrets.password.clone(),
+    };
+
+    self
+      .tokens
+      .tokens(
+        || {
+          get_access_token(
+            &self.transport,
+            &self.endpoints,
+            &self.secrets.public_key,
+            &self.secrets.private_key,
+          )
+        },
+        |access_token| async move {
+          get_session_token(&self.transport, &self.endpoints, &cred, &access_token).await
+        },
+      )
+      .await
+  }
+
+  /// Discards the stored tokens and requests a new OAuth2 access token and M2M session token.
+  /// # Panics
+  /// See [`get_access_token`](crate::api::get_access_token).
+  /// # Errors
+  /// Returns the first `Error` encountered while requesting the tokens.
+  pub async fn login(&self) -> Result<AuthTokens, Error> {
+    self.tokens.invalidate().await;
+    self.tokens().await
+  }
+
+  /// Calls `request` with the current [`AuthTokens`]. If ThingSpace rejects the tokens, they are
+  /// replaced and `request` is called a second time.
+  async fn authorized<R, F, Fut>(&self, mut request: F) -> Result<R, Error>
+  where
+    F: FnMut(AuthTokens) -> Fut,
+    Fut: Future<Output = Result<R, Error>>,
+  {
+    let tokens = self.tokens().await?;
+
+    match request(tokens.clone()).await {
+      Err(e) if e.is_auth_error() => {
+        self.tokens.invalidate_rejected(&tokens).await;
+        request(self.tokens().await?).await
+      }
+      result => result,
+    }
+  }
+
+  /// The [`Endpoints`] every request is sent to.
+  #[must_use]
+  pub fn endpoints(&self) -> &Endpoints {
+    &self.endpoints
+  }
+
+  /// Replaces the [`RetryPolicy`] used for every request.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::api::{HttpTransport, RetryPolicy, ThingSpaceClient};
+  ///
+  /// fn retry_nidd<T: HttpTransport>(client: ThingSpaceClient<T>) -> ThingSpaceClient<T> {
+  ///   client.with_retry_policy(RetryPolicy {
+  ///     retry_non_idempotent: true,
+  ///     ..Default::default()
+  ///   })
+  /// }
+  /// ```
+  #[must_use]
+  pub fn with_retry_policy(mut self, policy: RetryPolicy) -> ThingSpaceClient<T> {
+    self.transport.set_policy(policy);
+    self
+  }
+
+  /// The [`RetryPolicy`] used for every request.
+  #[must_use]
+  pub fn retry_policy(&self) -> &RetryPolicy {
+    self.transport.policy()
+  }
+
+  /// Makes every request wait on `limiter`. Clients of the same account should share one
+  /// [`RateLimiter`]. By default a client has its own limiter without any limits.
+  ///
+  /// # Example
+  /// ```rust
+  /// use std::sync::Arc;
+  /// use thingspace_sdk::api::{EndpointGroup, HttpTransport, Rate, RateLimiter, ThingSpaceClient};
+  ///
+  /// fn limit_nidd<T: HttpTransport>(client: ThingSpaceClient<T>) -> ThingSpaceClient<T> {
+  ///   let limiter = RateLimiter::default().with_limit(EndpointGroup::Nidd, Rate::per_second(5));
+  ///   client.with_rate_limiter(Arc::new(limiter))
+  /// }
+  /// ```
+  #[must_use]
+  pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> ThingSpaceClient<T> {
+    self.transport.transport_mut().set_limiter(limiter);
+    self
+  }
+
+  /// The [`RateLimiter`] every request waits on.
+  #[must_use]
+  pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+    self.transport.transport().limiter()
+  }
+
+  /// The [`HttpTransport`] every request is sent with.
+  #[must_use]
+  pub fn transport(&self) -> &T {
+    self.transport.transport().transport()
+  }
+
+  /// The billing account name used for every request.
+  #[must_use]
+  pub fn account_name(&self) -> &str {
+    &self.secrets.account_name
+  }
+
+  /// Returns the information and capabilities of the client's account as an
+  /// [`AccountInformation`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn account_information(&self) -> Result<AccountInformation, Error> {
+    self
+      .authorized(|tokens| async move {
+        account_information(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the device states that are valid for the client's account as an
+  /// [`AccountStatesResponse`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn account_states(&self) -> Result<AccountStatesResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        account_states(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the [`ServicePlan`]s available to the client's account.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn service_plans(&self) -> Result<Vec<ServicePlan>, Error> {
+    self
+      .authorized(|tokens| async move {
+        service_plans(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the [`RequestStatusResponse`] of the asynchronous request `request_id`.
+  /// Use [`ThingSpaceClient::request_handle`] to poll until the request finishes.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn request_status(&self, request_id: &str) -> Result<RequestStatusResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        request_status(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          request_id,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns a [`RequestHandle`] for the asynchronous request `request_id`, e.g. one whose
+  /// [`NiddRequest::request_id`](crate::models::NiddRequest::request_id) or
+  /// [`ActionResponse::request_id`](crate::models::ActionResponse::request_id) was stored earlier.
+  #[must_use]
+  pub fn request_handle(&self, request_id: impl Into<String>) -> RequestHandle<'_, T> {
+    RequestHandle::new(self, request_id)
+  }
+
+  /// Makes an API request for an Account Device List and returns a [`AccountDeviceListResponse`].
+  /// [`AccountDeviceListRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn devices_list(
+    &self,
+    adl: &mut AccountDeviceListRequest,
+  ) -> Result<AccountDeviceListResponse, Error> {
+    adl.account_name = Some(self.secrets.account_name.clone());
+    let adl: &AccountDeviceListRequest = adl;
+
+    self
+      .authorized(|tokens| async move {
+        devices_list(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          adl,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns a [`DeviceStream`] that requests `/devices/actions/list` one page at a time and
+  /// yields each [`Device`](crate::models::Device). The page size is
+  /// [`AccountDeviceListRequest::max_number_of_devices`]; use [`Paginated::max_total`] to stop
+  /// early. The stream ends after the first `Error`.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::api::{HttpTransport, ThingSpaceClient};
+  /// use thingspace_sdk::models::{AccountDeviceListRequest, Error};
+  ///
+  /// async fn first_500_ids<T: HttpTransport>(
+  ///   client: &ThingSpaceClient<T>,
+  /// ) -> Result<Vec<String>, Error> {
+  ///   let request = AccountDeviceListRequest {
+  ///     max_number_of_devices: Some(100),
+  ///     ..Default::default()
+  ///   };
+  ///
+  ///   let mut devices = client.devices_stream(request).max_total(500);
+  ///   let mut ids = Vec::new();
+  ///   while let Some(device) = devices.next().await {
+  ///     ids.push(device?.device_ids[0].id.clone());
+  ///   }
+  ///   Ok(ids)
+  /// }
+  /// ```
+  #[must_use]
+  pub fn devices_stream(&self, adl: AccountDeviceListRequest) -> DeviceStream<'_, T> {
+    Paginated::new(DeviceListSource::new(self, adl))
+  }
+
+  /// Sends a NIDD message and returns a [`RequestHandle`] for the request.
+  /// [`NiddMessage::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn send_nidd(&self, nidd_msg: &mut NiddMessage) -> Result<RequestHandle<'_, T>, Error> {
+    nidd_msg.account_name.clone_from(&self.secrets.account_name);
+    let nidd_msg: &NiddMessage = nidd_msg;
+
+    let response = self
+      .authorized(|tokens| async move {
+        send_nidd(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          nidd_msg,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Sets up or changes the NIDD configuration of the devices in a [`NiddConfigurationRequest`]
+  /// and returns a [`RequestHandle`] for the request. The result is sent to the `NiddService`
+  /// callback listener. [`NiddConfigurationRequest::account_name`] is set to the client's account
+  /// name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn configure_nidd(
+    &self,
+    configuration: &mut NiddConfigurationRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    configuration
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let configuration: &NiddConfigurationRequest = configuration;
+
+    let response = self
+      .authorized(|tokens| async move {
+        configure_nidd(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          configuration,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Returns the NIDD configuration of the devices in a [`NiddConfigurationListRequest`].
+  /// [`NiddConfigurationListRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn nidd_configuration(
+    &self,
+    list: &mut NiddConfigurationListRequest,
+  ) -> Result<Vec<NiddConfiguration>, Error> {
+    list.account_name.clone_from(&self.secrets.account_name);
+    let list: &NiddConfigurationListRequest = list;
+
+    self
+      .authorized(|tokens| async move {
+        nidd_configuration(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          list,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Subscribes to awake and sleep notifications for the SCEF devices in a
+  /// [`ReachabilitySubscriptionRequest`] and returns a [`RequestHandle`] for the request. The
+  /// notifications are sent to the `DiagnosticsService` callback listener as
+  /// [`DiagnosticsCallback`](crate::models::DiagnosticsCallback)s.
+  /// [`ReachabilitySubscriptionRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn subscribe_reachability(
+    &self,
+    subscription: &mut ReachabilitySubscriptionRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    subscription
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let subscription: &ReachabilitySubscriptionRequest = subscription;
+
+    let response = self
+      .authorized(|tokens| async move {
+        subscribe_reachability(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          subscription,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Ends the reachability subscription of the SCEF devices in a [`ReachabilityStatusRequest`]
+  /// and returns a [`RequestHandle`] for the request.
+  /// [`ReachabilityStatusRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn unsubscribe_reachability(
+    &self,
+    devices: &mut ReachabilityStatusRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    devices.account_name.clone_from(&self.secrets.account_name);
+    let devices: &ReachabilityStatusRequest = devices;
+
+    let response = self
+      .authorized(|tokens| async move {
+        unsubscribe_reachability(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          devices,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Requests the current reachability of the SCEF devices in a [`ReachabilityStatusRequest`]
+  /// and returns a [`RequestHandle`] for the request. The status is sent to the
+  /// `DiagnosticsService` callback listener as a
+  /// [`DiagnosticsCallback`](crate::models::DiagnosticsCallback).
+  /// [`ReachabilityStatusRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn reachability_status(
+    &self,
+    devices: &mut ReachabilityStatusRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    devices.account_name.clone_from(&self.secrets.account_name);
+    let devices: &ReachabilityStatusRequest = devices;
+
+    let response = self
+      .authorized(|tokens| async move {
+        reachability_status(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          devices,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Sends an SMS message to the devices in a [`SendSmsRequest`] and returns a [`RequestHandle`]
+  /// for the request. The `EnhancedConnectivityService` callback listener is notified when the
+  /// network sends the message, and `SMSDeliveryConfirmation` when a device acknowledges it.
+  /// [`SendSmsRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn send_sms(&self, sms: &mut SendSmsRequest) -> Result<RequestHandle<'_, T>, Error> {
+    sms.account_name.clone_from(&self.secrets.account_name);
+    let sms: &SendSmsRequest = sms;
+
+    let response = self
+      .authorized(|tokens| async move {
+        send_sms(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          sms,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Returns the SMS messages that devices sent to the client's account and that are still
+  /// queued as an [`SmsHistoryResponse`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn sms_history(&self) -> Result<SmsHistoryResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        sms_history(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Acknowledges the queued SMS messages of the client's account, so ThingSpace sends them and
+  /// every new message to the `EnhancedConnectivityService` callback listener instead.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn start_sms_callbacks(&self) -> Result<SuccessResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        start_sms_callbacks(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Activates service for the devices in an [`ActivateDevicesRequest`] and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`ActivateDevicesRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn activate_devices(
+    &self,
+    activation: &mut ActivateDevicesRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    activation
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let activation: &ActivateDevicesRequest = activation;
+
+    let response = self
+      .authorized(|tokens| async move {
+        activate_devices(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          activation,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Deactivates service for the devices in a [`DeactivateDevicesRequest`] and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`DeactivateDevicesRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn deactivate_devices(
+    &self,
+    deactivation: &mut DeactivateDevicesRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    deactivation
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let deactivation: &DeactivateDevicesRequest = deactivation;
+
+    let response = self
+      .authorized(|tokens| async move {
+        deactivate_devices(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          deactivation,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Suspends service for the devices in a [`DeviceStateRequest`] and returns a [`RequestHandle`]
+  /// for the request. The result is sent to the `CarrierService` callback listener.
+  /// [`DeviceStateRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn suspend_devices(
+    &self,
+    suspension: &mut DeviceStateRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    suspension
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let suspension: &DeviceStateRequest = suspension;
+
+    let response = self
+      .authorized(|tokens| async move {
+        suspend_devices(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          suspension,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Restores service for the suspended devices in a [`DeviceStateRequest`] and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`DeviceStateRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn restore_devices(
+    &self,
+    restoration: &mut DeviceStateRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    restoration
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let restoration: &DeviceStateRequest = restoration;
+
+    let response = self
+      .authorized(|tokens| async move {
+        restore_devices(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          restoration,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Deletes the deactivated devices in a [`DeleteDevicesRequest`] from the account and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`DeleteDevicesRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn delete_devices(
+    &self,
+    deletion: &mut DeleteDevicesRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    deletion.account_name.clone_from(&self.secrets.account_name);
+    let deletion: &DeleteDevicesRequest = deletion;
+
+    let response = self
+      .authorized(|tokens| async move {
+        delete_devices(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          deletion,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Changes the service plan of the devices in a [`ChangeServicePlanRequest`] and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`ChangeServicePlanRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn change_service_plan(
+    &self,
+    plan_change: &mut ChangeServicePlanRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    plan_change
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let plan_change: &ChangeServicePlanRequest = plan_change;
+
+    let response = self
+      .authorized(|tokens| async move {
+        change_service_plan(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          plan_change,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Moves the devices in a [`GoToStateRequest`] to a custom device state and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `StateService` callback listener.
+  /// [`GoToStateRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn go_to_state(
+    &self,
+    state_change: &mut GoToStateRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    state_change
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let state_change: &GoToStateRequest = state_change;
+
+    let response = self
+      .authorized(|tokens| async move {
+        go_to_state(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          state_change,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Sets the custom field values of the devices in an [`UpdateCustomFieldsRequest`] and returns a
+  /// [`RequestHandle`] for the request. The result is sent to the `CarrierService` callback
+  /// listener. [`UpdateCustomFieldsRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn update_custom_fields(
+    &self,
+    custom_fields: &mut UpdateCustomFieldsRequest,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    custom_fields
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let custom_fields: &UpdateCustomFieldsRequest = custom_fields;
+
+    let response = self
+      .authorized(|tokens| async move {
+        update_custom_fields(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          custom_fields,
+        )
+        .await
+      })
+      .await?;
+
+    Ok(RequestHandle::new(self, response.request_id))
+  }
+
+  /// Sets the custom field `key` of `device` to `value` with
+  /// [`ThingSpaceClient::update_custom_fields`] and returns a [`RequestHandle`] for the request.
+  /// Once ThingSpace has accepted the request, the value is also set with
+  /// [`Device::set_custom_field`], so `device` matches what ThingSpace stores after the request
+  /// succeeds.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn set_custom_field(
+    &self,
+    device: &mut Device,
+    key: CustomFieldKey,
+    value: &str,
+  ) -> Result<RequestHandle<'_, T>, Error> {
+    let mut custom_fields = UpdateCustomFieldsRequest {
+      devices: vec![DeviceIdList {
+        device_ids: device.device_ids.clone(),
+        ip_address: None,
+      }],
+      custom_fields_to_update: vec![CustomField::new(key, value)],
+      ..Default::default()
+    };
+
+    let handle = self.update_custom_fields(&mut custom_fields).await?;
+    device.set_custom_field(key, value);
+
+    Ok(handle)
+  }
+
+  /// Returns one page of the usage history of a single device as a [`DeviceUsageResponse`].
+  /// Use [`ThingSpaceClient::device_usage_stream`] to request every page.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn device_usage(
+    &self,
+    usage_request: &DeviceUsageRequest,
+  ) -> Result<DeviceUsageResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        device_usage(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          usage_request,
+        )
+        .await
+      })
+      .await
+  }
+  /// Returns a [`DeviceUsageStream`] that requests `/devices/usage/actions/list` one page at a
+  /// time and yields each [`UsageRecord`](crate::models::UsageRecord). The stream ends after the
+  /// first `Error`.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::api::{HttpTransport, ThingSpaceClient};
+  /// use thingspace_sdk::models::{DeviceUsageRequest, Error, UsageTotals};
+  ///
+  /// async fn total_usage<T: HttpTransport>(
+  ///   client: &ThingSpaceClient<T>,
+  ///   request: DeviceUsageRequest,
+  /// ) -> Result<UsageTotals, Error> {
+  ///   let records = client.device_usage_stream(request).try_collect().await?;
+  ///   Ok(records.iter().sum())
+  /// }
+  /// ```
+  #[must_use]
+  pub fn device_usage_stream(&self, usage_request: DeviceUsageRequest) -> DeviceUsageStream<'_, T> {
+    Paginated::new(DeviceUsageSource::new(self, usage_request))
+  }
+
+  /// Requests the total usage of the devices in an [`AggregateUsageRequest`] and returns the
+  /// [`AggregateUsageResponse`] transaction id. The result is sent to the `DeviceUsage` callback
+  /// listener. [`AggregateUsageRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn aggregate_usage(
+    &self,
+    usage_request: &mut AggregateUsageRequest,
+  ) -> Result<AggregateUsageResponse, Error> {
+    usage_request
+      .account_name
+      .clone_from(&self.secrets.account_name);
+    let usage_request: &AggregateUsageRequest = usage_request;
+
+    self
+      .authorized(|tokens| async move {
+        aggregate_usage(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          usage_request,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns one page of the connection history of a single device as a
+  /// [`ConnectionHistoryResponse`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn connection_history(
+    &self,
+    history_request: &ConnectionHistoryRequest,
+  ) -> Result<ConnectionHistoryResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        connection_history(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          history_request,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns a [`ConnectionHistoryStream`] that requests the connection history of a single device
+  /// one page at a time and yields each [`ConnectionEvent`](crate::models::ConnectionEvent), oldest
+  /// first. The stream ends after the first `Error`.
+  #[must_use]
+  pub fn connection_history_stream(
+    &self,
+    history_request: ConnectionHistoryRequest,
+  ) -> ConnectionHistoryStream<'_, T> {
+    Paginated::new(ConnectionHistorySource::new(self, history_request))
+  }
+
+  /// Returns one page of the provisioning history of a single device as a
+  /// [`ProvisioningHistoryResponse`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn provisioning_history(
+    &self,
+    history_request: &ProvisioningHistoryRequest,
+  ) -> Result<ProvisioningHistoryResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        provisioning_history(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          history_request,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns a [`ProvisioningHistoryStream`] that requests the provisioning history of a single
+  /// device one page at a time and yields each
+  /// [`ProvisioningEvent`](crate::models::ProvisioningEvent), oldest first. The stream ends after
+  /// the first `Error`.
+  #[must_use]
+  pub fn provisioning_history_stream(
+    &self,
+    history_request: ProvisioningHistoryRequest,
+  ) -> ProvisioningHistoryStream<'_, T> {
+    Paginated::new(ProvisioningHistorySource::new(self, history_request))
+  }
+
+  /// Creates the device group in a [`CreateDeviceGroupRequest`].
+  /// [`CreateDeviceGroupRequest::account_name`] is set to the client's account name.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn create_device_group(
+    &self,
+    group: &mut CreateDeviceGroupRequest,
+  ) -> Result<SuccessResponse, Error> {
+    group.account_name.clone_from(&self.secrets.account_name);
+    let group: &CreateDeviceGroupRequest = group;
+
+    self
+      .authorized(|tokens| async move {
+        create_device_group(
+          &self.transport,
+          &self.endpoints,
+          &tokens.access_token,
+          &tokens.session_token,
+          group,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the [`DeviceGroup`]s of the client's account.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn list_device_groups(&self) -> Result<Vec<DeviceGroup>, Error> {
+    self
+      .authorized(|tokens| async move {
+        list_device_groups(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the device group `group_name` and one page of the devices in it, see
+  /// [`DeviceGroupPageRequest`], as a [`DeviceGroupInformation`]. Use
+  /// [`ThingSpaceClient::device_group_stream`] to request every page.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn device_group(
+    &self,
+    group_name: &str,
+    page: &DeviceGroupPageRequest,
+  ) -> Result<DeviceGroupInformation, Error> {
+    self
+      .authorized(|tokens| async move {
+        device_group(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          group_name,
+          page,
+        )
+        .await
+      })
+      .await
+  }
+  /// Returns a [`DeviceGroupStream`] that requests the devices of the device group `group_name`
+  /// one page at a time. The page size is [`DeviceGroupPageRequest::max_number_of_devices`]; use
+  /// [`Paginated::max_total`] to stop early. The stream ends after the first `Error`.
+  #[must_use]
+  pub fn device_group_stream(
+    &self,
+    group_name: &str,
+    page: DeviceGroupPageRequest,
+  ) -> DeviceGroupStream<'_, T> {
+    Paginated::new(DeviceGroupSource::new(self, group_name, page))
+  }
+
+  /// Renames the device group `group_name`, changes its description, or adds and removes
+  /// devices, see [`UpdateDeviceGroupRequest`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn update_device_group(
+    &self,
+    group_name: &str,
+    update: &UpdateDeviceGroupRequest,
+  ) -> Result<SuccessResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        update_device_group(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          group_name,
+          update,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Adds `device_ids` to the device group `group_name`.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn add_devices_to_group(
+    &self,
+    group_name: &str,
+    device_ids: &[DeviceID],
+  ) -> Result<SuccessResponse, Error> {
+    let update = UpdateDeviceGroupRequest {
+      devices_to_add: device_ids.to_vec(),
+      ..Default::default()
+    };
+
+    self.update_device_group(group_name, &update).await
+  }
+
+  /// Removes `device_ids` from the device group `group_name` and moves them back to the default
+  /// group.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn remove_devices_from_group(
+    &self,
+    group_name: &str,
+    device_ids: &[DeviceID],
+  ) -> Result<SuccessResponse, Error> {
+    let update = UpdateDeviceGroupRequest {
+      devices_to_remove: device_ids.to_vec(),
+      ..Default::default()
+    };
+
+    self.update_device_group(group_name, &update).await
+  }
+
+  /// Deletes the device group `group_name`. Its devices are moved back to the default group.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn delete_device_group(&self, group_name: &str) -> Result<SuccessResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        delete_device_group(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          group_name,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Registers a given URL as a callback listener for the given [`CallbackListener::service_name`].
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn register_callback_listener(
+    &self,
+    cbl: &CallbackListener,
+  ) -> Result<CallbackListenerResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        register_callback_listener(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          cbl,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Removes the registered callback listener for the given `service_name`.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn deregister_callback_listener(
+    &self,
+    service_name: &str,
+  ) -> Result<CallbackListenerResponse, Error> {
+    self
+      .authorized(|tokens| async move {
+        deregister_callback_listener(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+          service_name,
+        )
+        .await
+      })
+      .await
+  }
+
+  /// Returns the name and endpoint URL of the callback listening services registered for
+  /// the client's account.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn list_callback_listeners(&self) -> Result<Vec<CallbackListener>, Error> {
+    self
+      .authorized(|tokens| async move {
+        list_callback_listeners(
+          &self.transport,
+          &self.endpoints,
+          &self.secrets.account_name,
+          &tokens.access_token,
+          &tokens.session_token,
+        )
+        .await
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::api::mock_transport::{MockTransport, client};
+  use std::sync::{Arc, Mutex};
+
+  const SESSION_EXPIRED: &str = r#"{"errorCode":"UnifiedWebService.REQUEST_FAILED.SessionToken.Expired","errorMessage":"Token expired."}"#;
+  const ACCOUNT: &str = "/accounts/0000123456-00001";
+
+  #[tokio::test]
+  async fn replays_a_rejected_request_once_with_new_tokens() {
+    let rejected = Arc::new(Mutex::new(false));
+    let transport = MockTransport::new(move |_| {
+      let mut rejected = rejected.lock().unwrap();
+      if *rejected {
+        (200, r#"{"accountName":"0000123456-00001"}"#.to_string())
+      } else {
+        *rejected = true;
+        (401, SESSION_EXPIRED.to_string())
+      }
+    });
+    let client = client(transport).await;
+
+    let account = client.account_information().await.unwrap();
+    assert_eq!(account.account_name, "0000123456-00001");
+
+    let transport = client.transport();
+    assert_eq!(transport.logins(), (2, 2));
+    let requests = transport.requests_to(ACCOUNT);
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].get_header("VZ-M2M-Token"), Some("session-1"));
+    assert_eq!(
+      requests[1].get_header("Authorization"),
+      Some("Bearer access-2")
+    );
+    assert_eq!(requests[1].get_header("VZ-M2M-Token"), Some("session-2"));
+  }
+
+  #[tokio::test]
+  async fn returns_a_second_auth_error() {
+    let transport = MockTransport::new(|_| (401, SESSION_EXPIRED.to_string()));
+    let client = client(transport).await;
+
+    let error = client.account_information().await.unwrap_err();
+    assert!(error.is_auth_error());
+    assert_eq!(client.transport().requests_to(ACCOUNT).len(), 2);
+    assert_eq!(client.transport().logins(), (2, 2));
+  }
+}
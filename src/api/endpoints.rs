@@ -0,0 +1,66 @@
+use crate::api::request_helpers::{LOGIN_URL, M2M_REST_API_V1};
+use serde::{Deserialize, Serialize};
+
+/// The base URLs used to reach the ThingSpace APIs.
+///
+/// [`Endpoints::default`] points at the production ThingSpace environment. Use
+/// [`Endpoints::new`] to target a local stand-in, a recording proxy or another Verizon environment.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::Endpoints;
+///
+/// let endpoints = Endpoints::new(
+///   "http://localhost:8080/api/m2m/v1/",
+///   "http://localhost:8080/api/ts/v1/oauth2/token",
+/// );
+/// assert_eq!(
+///   endpoints.m2m_url("/session/login"),
+///   "http://localhost:8080/api/m2m/v1/session/login"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Endpoints {
+  /// Base URL of the M2M REST API, without a trailing slash.
+  /// Defaults to `https://thingspace.verizon.com/api/m2m/v1`.
+  pub m2m_rest_api: String,
+  /// URL of the OAuth2 access token endpoint.
+  /// Defaults to `https://thingspace.verizon.com/api/ts/v1/oauth2/token`.
+  pub login_url: String,
+}
+
+impl Default for Endpoints {
+  fn default() -> Endpoints {
+    Endpoints {
+      m2m_rest_api: M2M_REST_API_V1.to_string(),
+      login_url: LOGIN_URL.to_string(),
+    }
+  }
+}
+
+impl Endpoints {
+  /// Creates an [`Endpoints`] from the M2M REST API base URL and the OAuth2 token URL.
+  /// A trailing `/` on `m2m_rest_api` is removed.
+  pub fn new(m2m_rest_api: impl Into<String>, login_url: impl Into<String>) -> Endpoints {
+    let mut m2m_rest_api = m2m_rest_api.into();
+    while m2m_rest_api.ends_with('/') {
+      m2m_rest_api.pop();
+    }
+
+    Endpoints {
+      m2m_rest_api,
+      login_url: login_url.into(),
+    }
+  }
+
+  /// Joins `path` (starting with `/`) onto the M2M REST API base URL.
+  #[must_use]
+  pub fn m2m_url(&self, path: &str) -> String {
+    let mut url = String::with_capacity(self.m2m_rest_api.len() + path.len());
+    url.push_str(&self.m2m_rest_api);
+    url.push_str(path);
+
+    url
+  }
+}
@@ -0,0 +1,105 @@
+use crate::api::{Endpoints, HttpRequest, HttpResponse, HttpTransport, ThingSpaceClient};
+use crate::models::{Error, Secrets};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The OAuth2 token URL of the [`Endpoints`] used by [`client`].
+pub(crate) const LOGIN_URL: &str = "https://thingspace.test/oauth2/token";
+/// The M2M REST API base URL of the [`Endpoints`] used by [`client`].
+pub(crate) const M2M_URL: &str = "https://thingspace.test/m2m/v1";
+
+type Responder = Box<dyn FnMut(&HttpRequest) -> (u16, String)>;
+
+/// A [`HttpTransport`] for unit tests that records every request and sleep without sending or
+/// waiting.
+///
+/// Access token and session requests are answered with numbered tokens, `access-1`,
+/// `session-1` and so on. Every other request is answered by the responder.
+pub(crate) struct MockTransport {
+  responder: Mutex<Responder>,
+  requests: Mutex<Vec<HttpRequest>>,
+  sleeps: Mutex<Vec<Duration>>,
+  logins: Mutex<(u32, u32)>,
+}
+
+impl MockTransport {
+  /// Creates a [`MockTransport`] that answers API requests with the status and body returned by
+  /// `responder`.
+  pub(crate) fn new(responder: impl FnMut(&HttpRequest) -> (u16, String) + 'static) -> Self {
+    MockTransport {
+      responder: Mutex::new(Box::new(responder)),
+      requests: Mutex::new(Vec::new()),
+      sleeps: Mutex::new(Vec::new()),
+      logins: Mutex::new((0, 0)),
+    }
+  }
+
+  /// The requests sent so far to URLs ending in `path`.
+  pub(crate) fn requests_to(&self, path: &str) -> Vec<HttpRequest> {
+    let requests = self.requests.lock().unwrap();
+    requests
+      .iter()
+      .filter(|request| request.url.ends_with(path))
+      .cloned()
+      .collect()
+  }
+
+  /// The number of access token and session requests sent so far.
+  pub(crate) fn logins(&self) -> (u32, u32) {
+    *self.logins.lock().unwrap()
+  }
+
+  /// Every duration slept so far.
+  pub(crate) fn sleeps(&self) -> Vec<Duration> {
+    self.sleeps.lock().unwrap().clone()
+  }
+}
+
+impl HttpTransport for MockTransport {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+    self.requests.lock().unwrap().push(request.clone());
+
+    let (status, body) = if request.url == LOGIN_URL {
+      let mut logins = self.logins.lock().unwrap();
+      logins.0 += 1;
+      let access_token = format!("access-{}", logins.0);
+      let body = serde_json::json!({
+        "access_token": access_token,
+        "scope": "ts.m2m",
+        "token_type": "Bearer",
+        "expires_in": 7200,
+      });
+      (200, body.to_string())
+    } else if request.url.ends_with("/session/login") {
+      let mut logins = self.logins.lock().unwrap();
+      logins.1 += 1;
+      let session_token = format!("session-{}", logins.1);
+      let body = serde_json::json!({ "sessionToken": session_token, "expiresIn": 1200 });
+      (200, body.to_string())
+    } else {
+      (self.responder.lock().unwrap())(&request)
+    };
+
+    Ok(HttpResponse {
+      status,
+      headers: Vec::new(),
+      body: body.into_bytes(),
+    })
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    self.sleeps.lock().unwrap().push(duration);
+  }
+}
+
+/// Creates a logged in [`ThingSpaceClient`] that sends its requests through `transport`.
+pub(crate) async fn client(transport: MockTransport) -> ThingSpaceClient<MockTransport> {
+  let secrets = Secrets {
+    account_name: "0000123456-00001".to_string(),
+    ..Default::default()
+  };
+
+  ThingSpaceClient::with_transport(secrets, Endpoints::new(M2M_URL, LOGIN_URL), transport)
+    .await
+    .unwrap()
+}
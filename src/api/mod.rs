@@ -1,37 +1,285 @@
 mod request_helpers;
 
+mod endpoints;
+/// Configurable base URLs for the ThingSpace APIs
+pub use endpoints::Endpoints;
+
+mod transport;
+/// A backend-agnostic HTTP request/response pair and the trait every backend implements
+pub use transport::CredentialsMode;
+pub use transport::HttpRequest;
+pub use transport::HttpResponse;
+pub use transport::HttpTransport;
+pub use transport::Method;
+
+mod retry;
+/// Retries transient failures with exponential backoff and jitter
+pub use retry::RetryPolicy;
+pub use retry::RetryTransport;
+
+mod rate_limit;
+/// Client-side token bucket rate limiting per account and endpoint group
+pub use rate_limit::EndpointGroup;
+pub use rate_limit::Rate;
+pub use rate_limit::RateLimitTransport;
+pub use rate_limit::RateLimiter;
+
+mod operations;
+
+mod pagination;
+/// Lazily requests the pages of paginated endpoints, such as `/devices/actions/list` and the
+/// device history endpoints
+pub use pagination::ConnectionHistorySource;
+pub use pagination::ConnectionHistoryStream;
+pub use pagination::DeviceGroupSource;
+pub use pagination::DeviceGroupStream;
+pub use pagination::DeviceListSource;
+pub use pagination::DeviceStream;
+pub use pagination::DeviceUsageSource;
+pub use pagination::DeviceUsageStream;
+pub use pagination::Page;
+pub use pagination::PageSource;
+pub use pagination::Paginated;
+pub use pagination::ProvisioningHistorySource;
+pub use pagination::ProvisioningHistoryStream;
+
+#[cfg(test)]
+mod mock_transport;
+
+mod request_handle;
+/// Polls asynchronous requests, such as NIDD messages and device actions, until they finish
+pub use request_handle::DEFAULT_POLL_INTERVAL;
+pub use request_handle::DEFAULT_WAIT_TIMEOUT;
+pub use request_handle::RequestHandle;
+pub use request_handle::WaitOutcome;
+
+mod client;
+/// A stateful client that owns the account secrets, tokens and HTTP transport
+pub use client::ThingSpaceClient;
+
+mod token_manager;
+/// Tracks token expiry and refreshes the OAuth2 access token and M2M session token
+pub use token_manager::AuthTokens;
+pub use token_manager::DEFAULT_REFRESH_MARGIN;
+pub use token_manager::TokenManager;
+
 #[cfg(feature = "wasm")]
 mod web;
 #[cfg(feature = "wasm")]
+pub use web::WebTransport;
+#[cfg(feature = "wasm")]
+/// Functions for use with the account information, states and service plan API endpoints
+pub use web::account_information;
+#[cfg(feature = "wasm")]
+pub use web::account_states;
+#[cfg(feature = "wasm")]
+/// Functions for use with "Connectivity Management" device action API endpoints
+pub use web::activate_devices;
+#[cfg(feature = "wasm")]
+/// Functions for use with the device usage reporting API endpoints
+pub use web::aggregate_usage;
+#[cfg(feature = "wasm")]
+pub use web::change_service_plan;
+#[cfg(feature = "wasm")]
+/// Functions for use with the NIDD configuration API endpoints
+pub use web::configure_nidd;
+#[cfg(feature = "wasm")]
+/// Functions for use with the connection and provisioning history API endpoints
+pub use web::connection_history;
+#[cfg(feature = "wasm")]
+/// Functions for use with the device group API endpoints
+pub use web::create_device_group;
+#[cfg(feature = "wasm")]
+pub use web::deactivate_devices;
+#[cfg(feature = "wasm")]
+pub use web::delete_device_group;
+#[cfg(feature = "wasm")]
+pub use web::delete_devices;
+#[cfg(feature = "wasm")]
+/// Functions for use with "Registered Callbacks Listeners" API endpoints
+pub use web::deregister_callback_listener;
+#[cfg(feature = "wasm")]
+pub use web::device_group;
+#[cfg(feature = "wasm")]
+pub use web::device_usage;
+#[cfg(feature = "wasm")]
+/// Functions for use with "Device Management" API endpoints, primarily `/devices/actions/list`
+pub use web::devices_list;
+#[cfg(feature = "wasm")]
 pub use web::get_access_token;
 #[cfg(feature = "wasm")]
 pub use web::get_session_token;
+#[cfg(feature = "wasm")]
+pub use web::go_to_state;
+#[cfg(feature = "wasm")]
+pub use web::list_callback_listeners;
+#[cfg(feature = "wasm")]
+pub use web::list_device_groups;
+#[cfg(feature = "wasm")]
+pub use web::nidd_configuration;
+#[cfg(feature = "wasm")]
+pub use web::provisioning_history;
+#[cfg(feature = "wasm")]
+/// Functions for use with the SCEF reachability diagnostics API endpoints
+pub use web::reachability_status;
+#[cfg(feature = "wasm")]
+pub use web::register_callback_listener;
+#[cfg(feature = "wasm")]
+pub use web::request_status;
+#[cfg(feature = "wasm")]
+pub use web::restore_devices;
+#[cfg(feature = "wasm")]
+pub use web::send_nidd;
+#[cfg(feature = "wasm")]
+/// Functions for use with the SMS API endpoints
+pub use web::send_sms;
+#[cfg(feature = "wasm")]
+pub use web::service_plans;
+#[cfg(feature = "wasm")]
+pub use web::sms_history;
+#[cfg(feature = "wasm")]
+pub use web::start_sms_callbacks;
+#[cfg(feature = "wasm")]
+pub use web::subscribe_reachability;
+#[cfg(feature = "wasm")]
+pub use web::suspend_devices;
+#[cfg(feature = "wasm")]
+pub use web::unsubscribe_reachability;
+#[cfg(feature = "wasm")]
+pub use web::update_custom_fields;
+#[cfg(feature = "wasm")]
+pub use web::update_device_group;
 
 #[cfg(feature = "worker")]
 mod worker;
 #[cfg(feature = "worker")]
+pub use worker::WorkerTransport;
+#[cfg(feature = "worker")]
+/// Functions for use with the account information, states and service plan API endpoints
+pub use worker::account_information;
+#[cfg(feature = "worker")]
+pub use worker::account_states;
+#[cfg(feature = "worker")]
+/// Functions for use with "Connectivity Management" device action API endpoints
+pub use worker::activate_devices;
+#[cfg(feature = "worker")]
+/// Functions for use with the device usage reporting API endpoints
+pub use worker::aggregate_usage;
+#[cfg(feature = "worker")]
+pub use worker::change_service_plan;
+#[cfg(feature = "worker")]
+/// Functions for use with the NIDD configuration API endpoints
+pub use worker::configure_nidd;
+#[cfg(feature = "worker")]
+/// Functions for use with the connection and provisioning history API endpoints
+pub use worker::connection_history;
+#[cfg(feature = "worker")]
+/// Functions for use with the device group API endpoints
+pub use worker::create_device_group;
+#[cfg(feature = "worker")]
+pub use worker::deactivate_devices;
+#[cfg(feature = "worker")]
+pub use worker::delete_device_group;
+#[cfg(feature = "worker")]
+pub use worker::delete_devices;
+#[cfg(feature = "worker")]
 /// Functions for use with "Registered Callbacks Listeners" API endpoints
 pub use worker::deregister_callback_listener;
 #[cfg(feature = "worker")]
+pub use worker::device_group;
+#[cfg(feature = "worker")]
+pub use worker::device_usage;
+#[cfg(feature = "worker")]
 /// Functions for use with "Device Management" API endpoints, primarily `/devices/actions/list`
 pub use worker::devices_list;
 #[cfg(feature = "worker")]
+/// Turns an `Error` into a proxied `worker::Response`
+pub use worker::error_response;
+#[cfg(feature = "worker")]
 pub use worker::get_access_token;
 #[cfg(feature = "worker")]
 pub use worker::get_session_token;
 #[cfg(feature = "worker")]
+pub use worker::go_to_state;
+#[cfg(feature = "worker")]
 pub use worker::list_callback_listeners;
 #[cfg(feature = "worker")]
+pub use worker::list_device_groups;
+#[cfg(feature = "worker")]
+pub use worker::nidd_configuration;
+#[cfg(feature = "worker")]
+pub use worker::provisioning_history;
+#[cfg(feature = "worker")]
+/// Functions for use with the SCEF reachability diagnostics API endpoints
+pub use worker::reachability_status;
+#[cfg(feature = "worker")]
 pub use worker::register_callback_listener;
 #[cfg(feature = "worker")]
+pub use worker::request_status;
+#[cfg(feature = "worker")]
+pub use worker::restore_devices;
+#[cfg(feature = "worker")]
 pub use worker::send_nidd;
+#[cfg(feature = "worker")]
+/// Functions for use with the SMS API endpoints
+pub use worker::send_sms;
+#[cfg(feature = "worker")]
+pub use worker::service_plans;
+#[cfg(feature = "worker")]
+pub use worker::sms_history;
+#[cfg(feature = "worker")]
+pub use worker::start_sms_callbacks;
+#[cfg(feature = "worker")]
+pub use worker::subscribe_reachability;
+#[cfg(feature = "worker")]
+pub use worker::suspend_devices;
+#[cfg(feature = "worker")]
+pub use worker::unsubscribe_reachability;
+#[cfg(feature = "worker")]
+pub use worker::update_custom_fields;
+#[cfg(feature = "worker")]
+pub use worker::update_device_group;
 
 #[cfg(feature = "reqwest")]
 mod native;
 #[cfg(feature = "reqwest")]
+pub use native::ReqwestTransport;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the account information, states and service plan API endpoints
+pub use native::account_information;
+#[cfg(feature = "reqwest")]
+pub use native::account_states;
+#[cfg(feature = "reqwest")]
+/// Functions for use with "Connectivity Management" device action API endpoints
+pub use native::activate_devices;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the device usage reporting API endpoints
+pub use native::aggregate_usage;
+#[cfg(feature = "reqwest")]
+pub use native::change_service_plan;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the NIDD configuration API endpoints
+pub use native::configure_nidd;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the connection and provisioning history API endpoints
+pub use native::connection_history;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the device group API endpoints
+pub use native::create_device_group;
+#[cfg(feature = "reqwest")]
+pub use native::deactivate_devices;
+#[cfg(feature = "reqwest")]
+pub use native::delete_device_group;
+#[cfg(feature = "reqwest")]
+pub use native::delete_devices;
+#[cfg(feature = "reqwest")]
 /// Functions for use with "Registered Callbacks Listeners" API endpoints
 pub use native::deregister_callback_listener;
 #[cfg(feature = "reqwest")]
+pub use native::device_group;
+#[cfg(feature = "reqwest")]
+pub use native::device_usage;
+#[cfg(feature = "reqwest")]
 /// Functions for use with "Device Management" API endpoints, primarily `/devices/actions/list`
 pub use native::devices_list;
 #[cfg(feature = "reqwest")]
@@ -39,8 +287,42 @@ pub use native::get_access_token;
 #[cfg(feature = "reqwest")]
 pub use native::get_session_token;
 #[cfg(feature = "reqwest")]
+pub use native::go_to_state;
+#[cfg(feature = "reqwest")]
 pub use native::list_callback_listeners;
 #[cfg(feature = "reqwest")]
+pub use native::list_device_groups;
+#[cfg(feature = "reqwest")]
+pub use native::nidd_configuration;
+#[cfg(feature = "reqwest")]
+pub use native::provisioning_history;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the SCEF reachability diagnostics API endpoints
+pub use native::reachability_status;
+#[cfg(feature = "reqwest")]
 pub use native::register_callback_listener;
 #[cfg(feature = "reqwest")]
+pub use native::request_status;
+#[cfg(feature = "reqwest")]
+pub use native::restore_devices;
+#[cfg(feature = "reqwest")]
 pub use native::send_nidd;
+#[cfg(feature = "reqwest")]
+/// Functions for use with the SMS API endpoints
+pub use native::send_sms;
+#[cfg(feature = "reqwest")]
+pub use native::service_plans;
+#[cfg(feature = "reqwest")]
+pub use native::sms_history;
+#[cfg(feature = "reqwest")]
+pub use native::start_sms_callbacks;
+#[cfg(feature = "reqwest")]
+pub use native::subscribe_reachability;
+#[cfg(feature = "reqwest")]
+pub use native::suspend_devices;
+#[cfg(feature = "reqwest")]
+pub use native::unsubscribe_reachability;
+#[cfg(feature = "reqwest")]
+pub use native::update_custom_fields;
+#[cfg(feature = "reqwest")]
+pub use native::update_device_group;
@@ -1,8 +1,6 @@
-use crate::api::request_helpers::{
-  BASE64_BUF_SIZE, LOGIN_URL, M2M_REST_API_V1, encode_login_field, oauth_field,
-};
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
 use crate::models::{Error, LoginResponse, Session, SessionRequestBody};
-use const_format::concatcp;
 
 /// Makes an API request for an OAuth2 access token and returns a [`LoginResponse`].
 /// # Panics
@@ -28,7 +26,7 @@ use const_format::concatcp;
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 /// use std::fs;
-/// use thingspace_sdk::api::get_access_token;
+/// use thingspace_sdk::api::{Endpoints, get_access_token};
 ///
 /// #[derive(Serialize, Deserialize, Debug, Clone)]
 /// #[allow(dead_code)]
@@ -46,6 +44,7 @@ use const_format::concatcp;
 ///   let client = reqwest::Client::new();
 ///
 ///   match thingspace_sdk::api::get_access_token(
+///     &Endpoints::default(),
 ///     &secrets.public_key,
 ///     &secrets.private_key,
 ///     Some(client.clone()),
@@ -65,43 +64,18 @@ use const_format::concatcp;
 /// }
 /// ```
 pub async fn get_access_token(
+  endpoints: &Endpoints,
   public_key: &str,
   private_key: &str,
   client: Option<reqwest::Client>,
 ) -> Result<LoginResponse, Error> {
-  let mut enc_buf = [0u8; BASE64_BUF_SIZE];
-  let auth = encode_login_field(public_key, private_key, &mut enc_buf)
-    .expect("Failed to encode login field");
-  let auth = std::str::from_utf8(auth)?.trim_end_matches('\0');
-
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let request = client
-    .post(LOGIN_URL)
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/x-www-form-urlencoded")
-    .header("Authorization", auth)
-    .body("grant_type=client_credentials")
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<LoginResponse>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
+  operations::get_access_token(
+    &default_transport(client),
+    endpoints,
+    public_key,
+    private_key,
+  )
+  .await
 }
 
 /// Makes an API request for a M2M session token and returns a [`Session`].
@@ -112,7 +86,7 @@ pub async fn get_access_token(
 /// ```rust
 /// use serde::{Deserialize, Serialize};
 /// use std::fs;
-/// use thingspace_sdk::api::get_session_token;
+/// use thingspace_sdk::api::{Endpoints, get_session_token};
 /// use thingspace_sdk::models::SessionRequestBody;
 ///
 /// #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -135,7 +109,14 @@ pub async fn get_access_token(
 ///     password: secrets.password.clone(),
 ///   };
 ///
-///   match thingspace_sdk::api::get_session_token(&user_info, access_token, Some(client)).await {
+///   match thingspace_sdk::api::get_session_token(
+///     &Endpoints::default(),
+///     &user_info,
+///     access_token,
+///     Some(client),
+///   )
+///   .await
+///   {
 ///     Ok(response) => {
 ///       println!(
 ///         "Session token: {}, Expires in: {}",
@@ -149,37 +130,10 @@ pub async fn get_access_token(
 /// }
 /// ```
 pub async fn get_session_token(
+  endpoints: &Endpoints,
   cred: &SessionRequestBody,
   access_token: &str,
   client: Option<reqwest::Client>,
 ) -> Result<Session, Error> {
-  let body = serde_json::to_string(cred)?;
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let request = client
-    .post(concatcp!(M2M_REST_API_V1, "/session/login"))
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/json")
-    .header("Authorization", oauth_field(access_token))
-    .body(body)
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<Session>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
+  operations::get_session_token(&default_transport(client), endpoints, cred, access_token).await
 }
@@ -0,0 +1,211 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ActionResponse, ActivateDevicesRequest, ChangeServicePlanRequest, DeactivateDevicesRequest,
+  DeleteDevicesRequest, DeviceStateRequest, Error, GoToStateRequest, UpdateCustomFieldsRequest,
+};
+
+/// Activates service for the devices in an [`ActivateDevicesRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::{Endpoints, activate_devices};
+/// use thingspace_sdk::models::{
+///   ActivateDevicesRequest, DeviceID, DeviceIdKind, DeviceIdList, LoginResponse, Session,
+/// };
+///
+/// async fn activate(account_name: &str, login: &LoginResponse, session: &Session) {
+///   let activation = ActivateDevicesRequest {
+///     account_name: account_name.to_string(),
+///     devices: vec![DeviceIdList {
+///       device_ids: vec![DeviceID {
+///         id: "990013907835573".to_string(),
+///         kind: DeviceIdKind::Imei,
+///       }],
+///       ip_address: None,
+///     }],
+///     service_plan: "M2MPlan".to_string(),
+///     mdn_zip_code: "98801".to_string(),
+///     ..Default::default()
+///   };
+///
+///   match activate_devices(
+///     &Endpoints::default(),
+///     &login.access_token,
+///     &session.session_token,
+///     &activation,
+///     None,
+///   )
+///   .await
+///   {
+///     Ok(response) => println!("Activation request id: {}", response.request_id),
+///     Err(error) => println!("{error:?}"),
+///   }
+/// }
+/// ```
+pub async fn activate_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  activation: &ActivateDevicesRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::activate_devices(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    activation,
+  )
+  .await
+}
+
+/// Deactivates service for the devices in a [`DeactivateDevicesRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn deactivate_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deactivation: &DeactivateDevicesRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::deactivate_devices(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    deactivation,
+  )
+  .await
+}
+
+/// Suspends service for the devices in a [`DeviceStateRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn suspend_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  suspension: &DeviceStateRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::suspend_devices(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    suspension,
+  )
+  .await
+}
+
+/// Restores service for the suspended devices in a [`DeviceStateRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn restore_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  restoration: &DeviceStateRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::restore_devices(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    restoration,
+  )
+  .await
+}
+
+/// Deletes the deactivated devices in a [`DeleteDevicesRequest`] from the account and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn delete_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deletion: &DeleteDevicesRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::delete_devices(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    deletion,
+  )
+  .await
+}
+
+/// Changes the service plan of the devices in a [`ChangeServicePlanRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn change_service_plan(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  plan_change: &ChangeServicePlanRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::change_service_plan(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    plan_change,
+  )
+  .await
+}
+
+/// Moves the devices in a [`GoToStateRequest`] to a custom device state and returns the
+/// [`ActionResponse`] request id. The result is sent to the `StateService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn go_to_state(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  state_change: &GoToStateRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::go_to_state(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    state_change,
+  )
+  .await
+}
+
+/// Sets the custom field values of the devices in an [`UpdateCustomFieldsRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn update_custom_fields(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  custom_fields: &UpdateCustomFieldsRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::update_custom_fields(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    custom_fields,
+  )
+  .await
+}
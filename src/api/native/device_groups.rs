@@ -0,0 +1,118 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  CreateDeviceGroupRequest, DeviceGroup, DeviceGroupInformation, DeviceGroupPageRequest, Error,
+  SuccessResponse, UpdateDeviceGroupRequest,
+};
+
+/// Creates the device group in a [`CreateDeviceGroupRequest`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn create_device_group(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  group: &CreateDeviceGroupRequest,
+  client: Option<reqwest::Client>,
+) -> Result<SuccessResponse, Error> {
+  operations::create_device_group(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    group,
+  )
+  .await
+}
+
+/// Returns the [`DeviceGroup`]s of a billing account.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn list_device_groups(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  client: Option<reqwest::Client>,
+) -> Result<Vec<DeviceGroup>, Error> {
+  operations::list_device_groups(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Returns the device group `group_name` and one page of the devices in it, see
+/// [`DeviceGroupPageRequest`], as a [`DeviceGroupInformation`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn device_group(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+  page: &DeviceGroupPageRequest,
+  client: Option<reqwest::Client>,
+) -> Result<DeviceGroupInformation, Error> {
+  operations::device_group(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    group_name,
+    page,
+  )
+  .await
+}
+
+/// Renames the device group `group_name`, changes its description, or adds and removes
+/// devices, see [`UpdateDeviceGroupRequest`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn update_device_group(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+  update: &UpdateDeviceGroupRequest,
+  client: Option<reqwest::Client>,
+) -> Result<SuccessResponse, Error> {
+  operations::update_device_group(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    group_name,
+    update,
+  )
+  .await
+}
+
+/// Deletes the device group `group_name`. Its devices are moved back to the default group.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn delete_device_group(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+  client: Option<reqwest::Client>,
+) -> Result<SuccessResponse, Error> {
+  operations::delete_device_group(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    group_name,
+  )
+  .await
+}
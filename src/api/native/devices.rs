@@ -1,8 +1,8 @@
-use crate::api::request_helpers::{M2M_REST_API_V1, SESSION_TOKEN_FIELD, oauth_field};
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
 use crate::models::{
   AccountDeviceListRequest, AccountDeviceListResponse, Error, NiddMessage, NiddRequest,
 };
-use const_format::concatcp;
 
 /// Makes an API request for an Account Device List and returns a [`AccountDeviceListResponse`].
 /// # Errors
@@ -11,24 +11,24 @@ use const_format::concatcp;
 /// # Example
 /// ```rust
 /// use std::fs;
-/// use thingspace_sdk::{Secrets, LoginResponse, Session};
-/// use thingspace_sdk::devices::{AccountDeviceListRequest, AccountDeviceListResponse, devices_list};
+/// use thingspace_sdk::api::{Endpoints, devices_list};
+/// use thingspace_sdk::models::{AccountDeviceListRequest, LoginResponse, Secrets, Session};
 ///
-/// fn device_list() {
+/// async fn device_list(login: &LoginResponse, session: &Session) {
 ///   let file = fs::read_to_string("./secrets.toml").unwrap();
 ///   let secrets = toml::from_str::<Secrets>(&file).expect("Failed to read from secrets.toml");
-///   let mut login = LoginResponse::default();
-///   let mut session = Session::default();
 ///   let mut device_request = AccountDeviceListRequest::default();
-///   let mut device_result = AccountDeviceListResponse::default();
 ///
 ///   match devices_list(
-///     &secrets,
+///     &Endpoints::default(),
+///     &secrets.account_name,
 ///     &login.access_token,
 ///     &session.session_token,
 ///     &mut device_request,
-///     &mut device_result,
-///   ) {
+///     None,
+///   )
+///   .await
+///   {
 ///     Ok(response) => {
 ///       println!("{:?}", response.devices[0]);
 ///     }
@@ -39,6 +39,7 @@ use const_format::concatcp;
 /// }
 /// ```
 pub async fn devices_list(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
@@ -47,72 +48,29 @@ pub async fn devices_list(
 ) -> Result<AccountDeviceListResponse, Error> {
   adl.account_name = Some(account_name.to_string());
 
-  let body = serde_json::to_string(adl)?;
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let request = client
-    .post(concatcp!(M2M_REST_API_V1, "/devices/actions/list"))
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/json")
-    .header(SESSION_TOKEN_FIELD, session_token)
-    .header("Authorization", oauth_field(access_token))
-    .body(body)
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<AccountDeviceListResponse>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
+  operations::devices_list(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    adl,
+  )
+  .await
 }
 
 pub async fn send_nidd(
+  endpoints: &Endpoints,
   access_token: &str,
   session_token: &str,
   nidd_msg: &mut NiddMessage,
   client: Option<reqwest::Client>,
 ) -> Result<NiddRequest, Error> {
-  let body = serde_json::to_string(nidd_msg)?;
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let request = client
-    .post(concatcp!(M2M_REST_API_V1, "/devices/nidd/message"))
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/json")
-    .header(SESSION_TOKEN_FIELD, session_token)
-    .header("Authorization", oauth_field(access_token))
-    .body(body)
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<NiddRequest>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
+  operations::send_nidd(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    nidd_msg,
+  )
+  .await
 }
@@ -0,0 +1,48 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ConnectionHistoryRequest, ConnectionHistoryResponse, Error, ProvisioningHistoryRequest,
+  ProvisioningHistoryResponse,
+};
+
+/// Returns one page of the connection history of a single device as a
+/// [`ConnectionHistoryResponse`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn connection_history(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ConnectionHistoryRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ConnectionHistoryResponse, Error> {
+  operations::connection_history(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    history_request,
+  )
+  .await
+}
+
+/// Returns one page of the provisioning history of a single device as a
+/// [`ProvisioningHistoryResponse`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn provisioning_history(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ProvisioningHistoryRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ProvisioningHistoryResponse, Error> {
+  operations::provisioning_history(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    history_request,
+  )
+  .await
+}
@@ -0,0 +1,47 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ActionResponse, Error, NiddConfiguration, NiddConfigurationListRequest, NiddConfigurationRequest,
+};
+
+/// Sets up or changes the NIDD configuration of the devices in a [`NiddConfigurationRequest`]
+/// and returns the [`ActionResponse`] request id. The result is sent to the `NiddService`
+/// callback listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn configure_nidd(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  configuration: &NiddConfigurationRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::configure_nidd(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    configuration,
+  )
+  .await
+}
+
+/// Returns the NIDD configuration of the devices in a [`NiddConfigurationListRequest`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn nidd_configuration(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  list: &NiddConfigurationListRequest,
+  client: Option<reqwest::Client>,
+) -> Result<Vec<NiddConfiguration>, Error> {
+  operations::nidd_configuration(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    list,
+  )
+  .await
+}
@@ -1,6 +1,6 @@
-use crate::api::request_helpers::{M2M_REST_API_V1, SESSION_TOKEN_FIELD, oauth_field};
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
 use crate::models::{CallbackListener, CallbackListenerResponse, Error};
-use const_format::concatcp;
 
 /// Registers a given URL as a callback listener for the given [`CallbackListener::service_name`] and account.
 /// # Errors
@@ -9,29 +9,32 @@ use const_format::concatcp;
 /// # Example
 /// ```rust
 /// use std::fs;
-/// use thingspace_sdk::registered_callback_listeners::{CallbackListener, CallbackListenerResponse, register_callback_listener};
-/// use thingspace_sdk::{LoginResponse, Secrets, Session};
+/// use thingspace_sdk::api::{Endpoints, register_callback_listener};
+/// use thingspace_sdk::models::{CallbackListener, LoginResponse, Secrets, Session};
 ///
-///
-/// fn set_callback_listener() {
+/// async fn set_callback_listener(login: &LoginResponse, session: &Session) {
 ///   let file = fs::read_to_string("./secrets.toml").unwrap();
 ///   let secrets = toml::from_str::<Secrets>(&file).expect("Failed to read from secrets.toml");
-///   let mut login = LoginResponse::default();
-///   let mut session = Session::default();
-///   let mut rcl = CallbackListener {
+///   let rcl = CallbackListener {
 ///     service_name: "CarrierService".to_string(),
 ///     url: "https://mock.thingspace.verizon.com/webhook".to_string(),
 ///     ..Default::default()
 ///   };
 ///
-///   let mut response = CallbackListenerResponse::default();
-///
-///   match register_callback_listener(&secrets, &login.access_token, &session.session_token, &mut rcl, &mut response) {
-///     Ok(_) => {
+///   match register_callback_listener(
+///     &Endpoints::default(),
+///     &secrets.account_name,
+///     &login.access_token,
+///     &session.session_token,
+///     &rcl,
+///     None,
+///   )
+///   .await
+///   {
+///     Ok(response) => {
 ///       println!(
 ///         "Account: {}\nService: {}\n",
-///         response.account_name,
-///         response.service_name,
+///         response.account_name, response.service_name,
 ///       );
 ///     }
 ///     Err(error) => {
@@ -41,46 +44,22 @@ use const_format::concatcp;
 /// }
 /// ```
 pub async fn register_callback_listener(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
   cbl: &CallbackListener,
   client: Option<reqwest::Client>,
 ) -> Result<CallbackListenerResponse, Error> {
-  let body = serde_json::to_string(cbl)?;
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let mut url = String::with_capacity(80);
-  url.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  url.push_str(account_name);
-
-  let request = client
-    .post(url)
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/json")
-    .header(SESSION_TOKEN_FIELD, session_token)
-    .header("Authorization", oauth_field(access_token))
-    .body(body)
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<CallbackListenerResponse>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
+  operations::register_callback_listener(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    cbl,
+  )
+  .await
 }
 
 /// Removes a registered callback listener for the given [`CallbackListener::service_name`] and account.
@@ -90,25 +69,27 @@ pub async fn register_callback_listener(
 /// # Example
 /// ```rust
 /// use std::fs;
-/// use thingspace_sdk::registered_callback_listeners::{CallbackListenerResponse, deregister_callback_listener};
-/// use thingspace_sdk::{LoginResponse, Secrets, Session};
+/// use thingspace_sdk::api::{Endpoints, deregister_callback_listener};
+/// use thingspace_sdk::models::{LoginResponse, Secrets, Session};
 ///
-///
-/// fn delete_callback_listener() {
+/// async fn delete_callback_listener(login: &LoginResponse, session: &Session) {
 ///   let file = fs::read_to_string("./secrets.toml").unwrap();
 ///   let secrets = toml::from_str::<Secrets>(&file).expect("Failed to read from secrets.toml");
-///   let mut login = LoginResponse::default();
-///   let mut session = Session::default();
-///   let service_name = "CarrierService".to_string();
-///
-///   let mut response = CallbackListenerResponse::default();
 ///
-///   match deregister_callback_listener(&secrets, &login.access_token, &session.session_token, &service_name, &mut response) {
-///     Ok(_) => {
+///   match deregister_callback_listener(
+///     &Endpoints::default(),
+///     &secrets.account_name,
+///     &login.access_token,
+///     &session.session_token,
+///     "CarrierService",
+///     None,
+///   )
+///   .await
+///   {
+///     Ok(response) => {
 ///       println!(
 ///         "Account: {}\nService: {}\n",
-///         response.account_name,
-///         response.service_name,
+///         response.account_name, response.service_name,
 ///       );
 ///     }
 ///     Err(error) => {
@@ -118,57 +99,22 @@ pub async fn register_callback_listener(
 /// }
 /// ```
 pub async fn deregister_callback_listener(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
   service_name: &str,
   client: Option<reqwest::Client>,
 ) -> Result<CallbackListenerResponse, Error> {
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let mut url = String::with_capacity(128);
-  url.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  url.push_str(account_name);
-  url.push_str("/name/");
-  url.push_str(service_name);
-
-  let request = client
-    .delete(url)
-    .header("Accept", "application/json")
-    .header("Content-Type", "application/json")
-    .header(SESSION_TOKEN_FIELD, session_token)
-    .header("Authorization", oauth_field(access_token))
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<CallbackListenerResponse>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
-
-  // *response = ureq::delete(url)
-  //   .header("Accept", "application/json")
-  //   .header("Content-Type", "application/json")
-  //   .header("Authorization", oauth_field(access_token))
-  //   .header(SESSION_TOKEN_FIELD, session_token)
-  //   .call()?
-  //   .body_mut()
-  //   .read_json::<CallbackListenerResponse>()?;
-
-  // Ok(response)
+  operations::deregister_callback_listener(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    service_name,
+  )
+  .await
 }
 
 /// Returns the name and endpoint URL of the callback listening services registered for a given account.
@@ -178,26 +124,27 @@ pub async fn deregister_callback_listener(
 /// # Example
 /// ```rust
 /// use std::fs;
-/// use thingspace_sdk::registered_callback_listeners::{CallbackListener, list_callback_listeners};
-/// use thingspace_sdk::{LoginResponse, Secrets, Session};
+/// use thingspace_sdk::api::{Endpoints, list_callback_listeners};
+/// use thingspace_sdk::models::{LoginResponse, Secrets, Session};
 ///
-///
-/// fn print_listeners() {
+/// async fn print_listeners(login: &LoginResponse, session: &Session) {
 ///   let file = fs::read_to_string("./secrets.toml").unwrap();
 ///   let secrets = toml::from_str::<Secrets>(&file).expect("Failed to read from secrets.toml");
-///   let mut login = LoginResponse::default();
-///   let mut session = Session::default();
-///   let mut rcls = vec![CallbackListener {
-///     account_name: Some(String::with_capacity(16)),
-///     ..Default::default()
-///   }];
 ///
-///   match list_callback_listeners(&secrets, &login.access_token, &session.session_token, &mut rcls) {
-///     Ok(_) => {
+///   match list_callback_listeners(
+///     &Endpoints::default(),
+///     &secrets.account_name,
+///     &login.access_token,
+///     &session.session_token,
+///     None,
+///   )
+///   .await
+///   {
+///     Ok(rcls) => {
 ///       for rcl in rcls {
 ///         println!(
-///           "\n Account-name: {}\nService: {}\nurl: {}\n",
-///           rcl.account_name.unwrap(),
+///           "Account-name: {}\nService: {}\nurl: {}\n",
+///           rcl.account_name.unwrap_or_default(),
 ///           rcl.service_name,
 ///           rcl.url
 ///         );
@@ -210,48 +157,18 @@ pub async fn deregister_callback_listener(
 /// }
 /// ```
 pub async fn list_callback_listeners(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
   client: Option<reqwest::Client>,
 ) -> Result<Vec<CallbackListener>, Error> {
-  let client = match client {
-    Some(c) => c,
-    None => reqwest::Client::new(),
-  };
-
-  let mut url = String::with_capacity(80);
-  url.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  url.push_str(account_name);
-
-  let request = client
-    .get(url)
-    .header("Accept", "application/json")
-    .header(SESSION_TOKEN_FIELD, session_token)
-    .header("Authorization", oauth_field(access_token))
-    .send()
-    .await;
-
-  match request {
-    Ok(response) => {
-      let status = response.status().as_u16();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response.json::<Vec<CallbackListener>>().await?)
-    }
-    Err(e) => {
-      println!("{e:?}");
-      Err(Error::Reqwest(e))
-    }
-  }
-
-  // *response = ureq::get(url)
-  //   .header("Accept", "application/json")
-  //   .header("Authorization", oauth_field(access_token))
-  //   .header(SESSION_TOKEN_FIELD, session_token)
-  //   .call()?
-  //   .body_mut()
-  //   .read_json::<Vec<CallbackListener>>()?;
+  operations::list_callback_listeners(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
 }
@@ -0,0 +1,67 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{ActionResponse, Error, SendSmsRequest, SmsHistoryResponse, SuccessResponse};
+
+/// Sends an SMS message to the devices in a [`SendSmsRequest`] and returns the
+/// [`ActionResponse`] request id. The `EnhancedConnectivityService` callback listener is notified
+/// when the network sends the message, and `SMSDeliveryConfirmation` when a device acknowledges it.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn send_sms(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  sms: &SendSmsRequest,
+  client: Option<reqwest::Client>,
+) -> Result<ActionResponse, Error> {
+  operations::send_sms(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    sms,
+  )
+  .await
+}
+
+/// Returns the SMS messages that devices sent to a billing account and that are still queued
+/// as an [`SmsHistoryResponse`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn sms_history(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  client: Option<reqwest::Client>,
+) -> Result<SmsHistoryResponse, Error> {
+  operations::sms_history(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Acknowledges the queued SMS messages of a billing account, so ThingSpace sends them and
+/// every new message to the `EnhancedConnectivityService` callback listener instead.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn start_sms_callbacks(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  client: Option<reqwest::Client>,
+) -> Result<SuccessResponse, Error> {
+  operations::start_sms_callbacks(
+    &default_transport(client),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
@@ -0,0 +1,88 @@
+use crate::api::{HttpRequest, HttpResponse, HttpTransport, Method, RetryPolicy, RetryTransport};
+use crate::models::Error;
+use std::future::Future;
+use std::time::Duration;
+
+/// A [`HttpTransport`] that sends requests with a [`reqwest::Client`].
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+  client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+  /// Creates a [`ReqwestTransport`]. If `client` is `None` a new [`reqwest::Client`] is created.
+  #[must_use]
+  pub fn new(client: Option<reqwest::Client>) -> ReqwestTransport {
+    let client = match client {
+      Some(c) => c,
+      None => reqwest::Client::new(),
+    };
+
+    ReqwestTransport { client }
+  }
+
+  /// The underlying [`reqwest::Client`].
+  #[must_use]
+  pub fn client(&self) -> &reqwest::Client {
+    &self.client
+  }
+}
+
+/// The transport used by the native API functions: a [`ReqwestTransport`] with the default
+/// [`RetryPolicy`].
+/// Requests are not rate limited, see [`RateLimiter`](crate::api::RateLimiter).
+pub(crate) fn default_transport(
+  client: Option<reqwest::Client>,
+) -> RetryTransport<ReqwestTransport> {
+  RetryTransport::new(ReqwestTransport::new(client), RetryPolicy::default())
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+  fn from(client: reqwest::Client) -> Self {
+    ReqwestTransport { client }
+  }
+}
+
+impl HttpTransport for ReqwestTransport {
+  fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse, Error>> {
+    let method = match request.method {
+      Method::Get => reqwest::Method::GET,
+      Method::Post => reqwest::Method::POST,
+      Method::Put => reqwest::Method::PUT,
+      Method::Delete => reqwest::Method::DELETE,
+    };
+
+    let mut builder = self.client.request(method, request.url);
+    for (name, value) in request.headers {
+      builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body {
+      builder = builder.body(body);
+    }
+
+    async move {
+      match builder.send().await {
+        Ok(response) => {
+          let status = response.status().as_u16();
+          let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(n, v)| Some((n.to_string(), v.to_str().ok()?.to_string())))
+            .collect();
+          let body = response.bytes().await?.to_vec();
+
+          Ok(HttpResponse {
+            status,
+            headers,
+            body,
+          })
+        }
+        Err(e) => Err(Error::Reqwest(e)),
+      }
+    }
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    tokio::time::sleep(duration).await;
+  }
+}
@@ -0,0 +1,47 @@
+use crate::api::native::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  AggregateUsageRequest, AggregateUsageResponse, DeviceUsageRequest, DeviceUsageResponse, Error,
+};
+
+/// Returns the usage history of a single device as a [`DeviceUsageResponse`].
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn device_usage(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &DeviceUsageRequest,
+  client: Option<reqwest::Client>,
+) -> Result<DeviceUsageResponse, Error> {
+  operations::device_usage(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    usage_request,
+  )
+  .await
+}
+
+/// Requests the total usage of the devices in an [`AggregateUsageRequest`] and returns the
+/// [`AggregateUsageResponse`] transaction id. The result is sent to the `DeviceUsage` callback
+/// listener.
+/// # Errors
+/// Returns HTTP response code or `std::error::Error`.
+pub async fn aggregate_usage(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &AggregateUsageRequest,
+  client: Option<reqwest::Client>,
+) -> Result<AggregateUsageResponse, Error> {
+  operations::aggregate_usage(
+    &default_transport(client),
+    endpoints,
+    access_token,
+    session_token,
+    usage_request,
+  )
+  .await
+}
@@ -0,0 +1,45 @@
+use crate::api::request_helpers::{BASE64_BUF_SIZE, encode_login_field};
+use crate::api::{CredentialsMode, EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{Error, LoginResponse, Session, SessionRequestBody};
+
+/// Requests an OAuth2 access token.
+/// # Panics
+/// If the `public_key` and `private_key` do not fit into the login field buffers, see
+// `encode_login_field`.
+pub async fn get_access_token<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  public_key: &str,
+  private_key: &str,
+) -> Result<LoginResponse, Error> {
+  let mut enc_buf = [0u8; BASE64_BUF_SIZE];
+  let auth = encode_login_field(public_key, private_key, &mut enc_buf)
+    .expect("Failed to encode login field");
+  let auth = std::str::from_utf8(auth)?.trim_end_matches('\0');
+
+  let request = HttpRequest::new(Method::Post, endpoints.login_url.clone())
+    .group(EndpointGroup::Auth)
+    .header("Content-Type", "application/x-www-form-urlencoded")
+    .header("Authorization", auth)
+    .body("grant_type=client_credentials")
+    .credentials(CredentialsMode::Include)
+    .idempotent(true);
+
+  transport.send(request).await?.json()
+}
+
+/// Requests a M2M session token.
+pub async fn get_session_token<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  cred: &SessionRequestBody,
+  access_token: &str,
+) -> Result<Session, Error> {
+  let request = HttpRequest::new(Method::Post, endpoints.m2m_url("/session/login"))
+    .group(EndpointGroup::Auth)
+    .bearer(access_token)
+    .idempotent(true)
+    .json(cred)?;
+
+  transport.send(request).await?.json()
+}
@@ -0,0 +1,73 @@
+use crate::api::request_helpers::push_path_segment;
+use crate::api::{Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  AccountInformation, AccountStatesResponse, Error, RequestStatusResponse, ServicePlan,
+};
+
+/// `GET /accounts/{account_name}`
+pub async fn account_information<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<AccountInformation, Error> {
+  let mut url = endpoints.m2m_url("/accounts/");
+  push_path_segment(&mut url, account_name);
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /accounts/{account_name}/states`
+pub async fn account_states<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<AccountStatesResponse, Error> {
+  let mut url = endpoints.m2m_url("/accounts/");
+  push_path_segment(&mut url, account_name);
+  url.push_str("/states");
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /plans/{account_name}`
+pub async fn service_plans<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<Vec<ServicePlan>, Error> {
+  let mut url = endpoints.m2m_url("/plans/");
+  push_path_segment(&mut url, account_name);
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /accounts/{account_name}/requests/{request_id}`
+pub async fn request_status<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  request_id: &str,
+) -> Result<RequestStatusResponse, Error> {
+  let mut url = endpoints.m2m_url("/accounts/");
+  push_path_segment(&mut url, account_name);
+  url.push_str("/requests/");
+  push_path_segment(&mut url, request_id);
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
@@ -0,0 +1,187 @@
+//! Device actions change billing state and are processed asynchronously, so none of them are
+//! idempotent: a repeated request is queued again and may fail or be billed again.
+
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  ActionResponse, ActivateDevicesRequest, ChangeServicePlanRequest, DeactivateDevicesRequest,
+  DeleteDevicesRequest, DeviceStateRequest, Error, GoToStateRequest, UpdateCustomFieldsRequest,
+};
+use serde::Serialize;
+
+async fn device_action<T: HttpTransport, B: Serialize>(
+  transport: &T,
+  endpoints: &Endpoints,
+  method: Method,
+  path: &str,
+  access_token: &str,
+  session_token: &str,
+  body: &B,
+) -> Result<ActionResponse, Error> {
+  let request = HttpRequest::new(method, endpoints.m2m_url(path))
+    .group(EndpointGroup::Devices)
+    .authorize(access_token, session_token)
+    .idempotent(false)
+    .json(body)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/actions/activate`
+pub async fn activate_devices<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  activation: &ActivateDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Post,
+    "/devices/actions/activate",
+    access_token,
+    session_token,
+    activation,
+  )
+  .await
+}
+
+/// `POST /devices/actions/deactivate`
+pub async fn deactivate_devices<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deactivation: &DeactivateDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Post,
+    "/devices/actions/deactivate",
+    access_token,
+    session_token,
+    deactivation,
+  )
+  .await
+}
+
+/// `POST /devices/actions/suspend`
+pub async fn suspend_devices<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  suspension: &DeviceStateRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Post,
+    "/devices/actions/suspend",
+    access_token,
+    session_token,
+    suspension,
+  )
+  .await
+}
+
+/// `POST /devices/actions/restore`
+pub async fn restore_devices<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  restoration: &DeviceStateRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Post,
+    "/devices/actions/restore",
+    access_token,
+    session_token,
+    restoration,
+  )
+  .await
+}
+
+/// `POST /devices/actions/delete`
+pub async fn delete_devices<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deletion: &DeleteDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Post,
+    "/devices/actions/delete",
+    access_token,
+    session_token,
+    deletion,
+  )
+  .await
+}
+
+/// `PUT /devices/actions/plan`
+pub async fn change_service_plan<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  plan_change: &ChangeServicePlanRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Put,
+    "/devices/actions/plan",
+    access_token,
+    session_token,
+    plan_change,
+  )
+  .await
+}
+
+/// `PUT /devices/actions/gotostate`
+pub async fn go_to_state<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  state_change: &GoToStateRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Put,
+    "/devices/actions/gotostate",
+    access_token,
+    session_token,
+    state_change,
+  )
+  .await
+}
+
+/// `PUT /devices/actions/customFields`
+pub async fn update_custom_fields<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  custom_fields: &UpdateCustomFieldsRequest,
+) -> Result<ActionResponse, Error> {
+  device_action(
+    transport,
+    endpoints,
+    Method::Put,
+    "/devices/actions/customFields",
+    access_token,
+    session_token,
+    custom_fields,
+  )
+  .await
+}
@@ -0,0 +1,111 @@
+use crate::api::request_helpers::push_path_segment;
+use crate::api::{Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  CreateDeviceGroupRequest, DeviceGroup, DeviceGroupInformation, DeviceGroupPageRequest, Error,
+  SuccessResponse, UpdateDeviceGroupRequest,
+};
+
+fn group_url(endpoints: &Endpoints, account_name: &str, group_name: &str) -> String {
+  let mut url = endpoints.m2m_url("/groups/");
+  push_path_segment(&mut url, account_name);
+  url.push_str("/name/");
+  push_path_segment(&mut url, group_name);
+
+  url
+}
+
+/// `POST /groups`. Creating the same group twice fails, so it is not idempotent.
+pub async fn create_device_group<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  group: &CreateDeviceGroupRequest,
+) -> Result<SuccessResponse, Error> {
+  let request = HttpRequest::new(Method::Post, endpoints.m2m_url("/groups"))
+    .authorize(access_token, session_token)
+    .json(group)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /groups/{account_name}`
+pub async fn list_device_groups<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<Vec<DeviceGroup>, Error> {
+  let mut url = endpoints.m2m_url("/groups/");
+  push_path_segment(&mut url, account_name);
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /groups/{account_name}/name/{group_name}?maxNumberOfDevices&largestDeviceIdSeen`
+pub async fn device_group<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+  page: &DeviceGroupPageRequest,
+) -> Result<DeviceGroupInformation, Error> {
+  let mut url = group_url(endpoints, account_name, group_name);
+  let mut separator = '?';
+  for (name, value) in [
+    ("maxNumberOfDevices", page.max_number_of_devices),
+    ("largestDeviceIdSeen", page.largest_device_id_seen),
+  ] {
+    if let Some(value) = value {
+      url.push(separator);
+      url.push_str(name);
+      url.push('=');
+      url.push_str(&value.to_string());
+      separator = '&';
+    }
+  }
+
+  let request = HttpRequest::new(Method::Get, url).authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `PUT /groups/{account_name}/name/{group_name}`
+pub async fn update_device_group<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+  update: &UpdateDeviceGroupRequest,
+) -> Result<SuccessResponse, Error> {
+  let request = HttpRequest::new(Method::Put, group_url(endpoints, account_name, group_name))
+    .authorize(access_token, session_token)
+    .json(update)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `DELETE /groups/{account_name}/name/{group_name}`
+pub async fn delete_device_group<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  group_name: &str,
+) -> Result<SuccessResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Delete,
+    group_url(endpoints, account_name, group_name),
+  )
+  .authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
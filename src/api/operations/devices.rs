@@ -0,0 +1,37 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  AccountDeviceListRequest, AccountDeviceListResponse, Error, NiddMessage, NiddRequest,
+};
+
+/// `POST /devices/actions/list`
+pub async fn devices_list<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  adl: &AccountDeviceListRequest,
+) -> Result<AccountDeviceListResponse, Error> {
+  let request = HttpRequest::new(Method::Post, endpoints.m2m_url("/devices/actions/list"))
+    .group(EndpointGroup::Devices)
+    .authorize(access_token, session_token)
+    .idempotent(true)
+    .json(adl)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/nidd/message`. Every call sends another message, so it is not idempotent.
+pub async fn send_nidd<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  nidd_msg: &NiddMessage,
+) -> Result<NiddRequest, Error> {
+  let request = HttpRequest::new(Method::Post, endpoints.m2m_url("/devices/nidd/message"))
+    .group(EndpointGroup::Nidd)
+    .authorize(access_token, session_token)
+    .json(nidd_msg)?;
+
+  transport.send(request).await?.json()
+}
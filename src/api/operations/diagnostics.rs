@@ -0,0 +1,87 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  ActionResponse, Error, ReachabilityStatusRequest, ReachabilitySubscriptionRequest,
+};
+use serde::Serialize;
+
+/// Sends `body` to `/devices/diagnostics/reachability/actions/{action}`.
+async fn reachability_action<T: HttpTransport, B: Serialize>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  action: &str,
+  idempotent: bool,
+  body: &B,
+) -> Result<ActionResponse, Error> {
+  let mut url = endpoints.m2m_url("/devices/diagnostics/reachability/actions/");
+  url.push_str(action);
+
+  let request = HttpRequest::new(Method::Post, url)
+    .group(EndpointGroup::Devices)
+    .authorize(access_token, session_token)
+    .idempotent(idempotent)
+    .json(body)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/diagnostics/reachability/actions/subscribe`
+pub async fn subscribe_reachability<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  subscription: &ReachabilitySubscriptionRequest,
+) -> Result<ActionResponse, Error> {
+  reachability_action(
+    transport,
+    endpoints,
+    access_token,
+    session_token,
+    "subscribe",
+    false,
+    subscription,
+  )
+  .await
+}
+
+/// `POST /devices/diagnostics/reachability/actions/unsubscribe`
+pub async fn unsubscribe_reachability<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  devices: &ReachabilityStatusRequest,
+) -> Result<ActionResponse, Error> {
+  reachability_action(
+    transport,
+    endpoints,
+    access_token,
+    session_token,
+    "unsubscribe",
+    false,
+    devices,
+  )
+  .await
+}
+
+/// `POST /devices/diagnostics/reachability/actions/status`
+pub async fn reachability_status<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  devices: &ReachabilityStatusRequest,
+) -> Result<ActionResponse, Error> {
+  reachability_action(
+    transport,
+    endpoints,
+    access_token,
+    session_token,
+    "status",
+    true,
+    devices,
+  )
+  .await
+}
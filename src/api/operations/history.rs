@@ -0,0 +1,45 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  ConnectionHistoryRequest, ConnectionHistoryResponse, Error, ProvisioningHistoryRequest,
+  ProvisioningHistoryResponse,
+};
+
+/// `POST /devices/connections/actions/listHistory`
+pub async fn connection_history<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ConnectionHistoryRequest,
+) -> Result<ConnectionHistoryResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Post,
+    endpoints.m2m_url("/devices/connections/actions/listHistory"),
+  )
+  .group(EndpointGroup::Devices)
+  .authorize(access_token, session_token)
+  .idempotent(true)
+  .json(history_request)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/history/actions/list`
+pub async fn provisioning_history<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ProvisioningHistoryRequest,
+) -> Result<ProvisioningHistoryResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Post,
+    endpoints.m2m_url("/devices/history/actions/list"),
+  )
+  .group(EndpointGroup::Devices)
+  .authorize(access_token, session_token)
+  .idempotent(true)
+  .json(history_request)?;
+
+  transport.send(request).await?.json()
+}
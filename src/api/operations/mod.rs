@@ -0,0 +1,65 @@
+//! Every ThingSpace endpoint, implemented once on top of [`HttpTransport`].
+//!
+//! The `native`, `worker` and `web` modules, and [`ThingSpaceClient`], are thin wrappers that
+//! pick a transport and call into here.
+//!
+//! [`HttpTransport`]: crate::api::HttpTransport
+//! [`ThingSpaceClient`]: crate::api::ThingSpaceClient
+
+mod access;
+pub use access::get_access_token;
+pub use access::get_session_token;
+
+mod account;
+pub use account::account_information;
+pub use account::account_states;
+pub use account::request_status;
+pub use account::service_plans;
+
+mod device_actions;
+pub use device_actions::activate_devices;
+pub use device_actions::change_service_plan;
+pub use device_actions::deactivate_devices;
+pub use device_actions::delete_devices;
+pub use device_actions::go_to_state;
+pub use device_actions::restore_devices;
+pub use device_actions::suspend_devices;
+pub use device_actions::update_custom_fields;
+
+mod device_groups;
+pub use device_groups::create_device_group;
+pub use device_groups::delete_device_group;
+pub use device_groups::device_group;
+pub use device_groups::list_device_groups;
+pub use device_groups::update_device_group;
+
+mod diagnostics;
+pub use diagnostics::reachability_status;
+pub use diagnostics::subscribe_reachability;
+pub use diagnostics::unsubscribe_reachability;
+
+mod devices;
+pub use devices::devices_list;
+pub use devices::send_nidd;
+
+mod history;
+pub use history::connection_history;
+pub use history::provisioning_history;
+
+mod nidd;
+pub use nidd::configure_nidd;
+pub use nidd::nidd_configuration;
+
+mod registered_callback_listeners;
+pub use registered_callback_listeners::deregister_callback_listener;
+pub use registered_callback_listeners::list_callback_listeners;
+pub use registered_callback_listeners::register_callback_listener;
+
+mod sms;
+pub use sms::send_sms;
+pub use sms::sms_history;
+pub use sms::start_sms_callbacks;
+
+mod usage;
+pub use usage::aggregate_usage;
+pub use usage::device_usage;
@@ -0,0 +1,43 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  ActionResponse, Error, NiddConfiguration, NiddConfigurationListRequest, NiddConfigurationRequest,
+};
+
+/// `PUT /devices/nidd/configuration`
+pub async fn configure_nidd<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  configuration: &NiddConfigurationRequest,
+) -> Result<ActionResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Put,
+    endpoints.m2m_url("/devices/nidd/configuration"),
+  )
+  .group(EndpointGroup::Nidd)
+  .authorize(access_token, session_token)
+  .json(configuration)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/nidd/configuration/actions/list`
+pub async fn nidd_configuration<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  list: &NiddConfigurationListRequest,
+) -> Result<Vec<NiddConfiguration>, Error> {
+  let request = HttpRequest::new(
+    Method::Post,
+    endpoints.m2m_url("/devices/nidd/configuration/actions/list"),
+  )
+  .group(EndpointGroup::Nidd)
+  .authorize(access_token, session_token)
+  .idempotent(true)
+  .json(list)?;
+
+  transport.send(request).await?.json()
+}
@@ -0,0 +1,63 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{CallbackListener, CallbackListenerResponse, Error};
+
+/// `POST /callbacks/{account_name}`. Registering a service again replaces its URL, so it is
+/// idempotent.
+pub async fn register_callback_listener<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  cbl: &CallbackListener,
+) -> Result<CallbackListenerResponse, Error> {
+  let mut url = endpoints.m2m_url("/callbacks/");
+  url.push_str(account_name);
+
+  let request = HttpRequest::new(Method::Post, url)
+    .group(EndpointGroup::Callbacks)
+    .authorize(access_token, session_token)
+    .idempotent(true)
+    .json(cbl)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `DELETE /callbacks/{account_name}/name/{service_name}`
+pub async fn deregister_callback_listener<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  service_name: &str,
+) -> Result<CallbackListenerResponse, Error> {
+  let mut url = endpoints.m2m_url("/callbacks/");
+  url.push_str(account_name);
+  url.push_str("/name/");
+  url.push_str(service_name);
+
+  let request = HttpRequest::new(Method::Delete, url)
+    .group(EndpointGroup::Callbacks)
+    .authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /callbacks/{account_name}`
+pub async fn list_callback_listeners<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<Vec<CallbackListener>, Error> {
+  let mut url = endpoints.m2m_url("/callbacks/");
+  url.push_str(account_name);
+
+  let request = HttpRequest::new(Method::Get, url)
+    .group(EndpointGroup::Callbacks)
+    .authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
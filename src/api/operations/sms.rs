@@ -0,0 +1,56 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{ActionResponse, Error, SendSmsRequest, SmsHistoryResponse, SuccessResponse};
+
+/// `POST /sms`. Every call sends another message, so it is not idempotent.
+pub async fn send_sms<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  sms: &SendSmsRequest,
+) -> Result<ActionResponse, Error> {
+  let request = HttpRequest::new(Method::Post, endpoints.m2m_url("/sms"))
+    .group(EndpointGroup::Sms)
+    .authorize(access_token, session_token)
+    .json(sms)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `GET /sms/{account_name}/history`
+pub async fn sms_history<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<SmsHistoryResponse, Error> {
+  let mut url = endpoints.m2m_url("/sms/");
+  url.push_str(account_name);
+  url.push_str("/history");
+
+  let request = HttpRequest::new(Method::Get, url)
+    .group(EndpointGroup::Sms)
+    .authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
+
+/// `PUT /sms/{account_name}/startCallbacks`
+pub async fn start_sms_callbacks<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<SuccessResponse, Error> {
+  let mut url = endpoints.m2m_url("/sms/");
+  url.push_str(account_name);
+  url.push_str("/startCallbacks");
+
+  let request = HttpRequest::new(Method::Put, url)
+    .group(EndpointGroup::Sms)
+    .authorize(access_token, session_token);
+
+  transport.send(request).await?.json()
+}
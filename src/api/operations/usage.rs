@@ -0,0 +1,45 @@
+use crate::api::{EndpointGroup, Endpoints, HttpRequest, HttpTransport, Method};
+use crate::models::{
+  AggregateUsageRequest, AggregateUsageResponse, DeviceUsageRequest, DeviceUsageResponse, Error,
+};
+
+/// `POST /devices/usage/actions/list`
+pub async fn device_usage<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &DeviceUsageRequest,
+) -> Result<DeviceUsageResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Post,
+    endpoints.m2m_url("/devices/usage/actions/list"),
+  )
+  .group(EndpointGroup::Devices)
+  .authorize(access_token, session_token)
+  .idempotent(true)
+  .json(usage_request)?;
+
+  transport.send(request).await?.json()
+}
+
+/// `POST /devices/usage/actions/list/aggregate`. The report is read-only, so a repeated request
+/// only sends another callback and is safe to retry.
+pub async fn aggregate_usage<T: HttpTransport>(
+  transport: &T,
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &AggregateUsageRequest,
+) -> Result<AggregateUsageResponse, Error> {
+  let request = HttpRequest::new(
+    Method::Post,
+    endpoints.m2m_url("/devices/usage/actions/list/aggregate"),
+  )
+  .group(EndpointGroup::Devices)
+  .authorize(access_token, session_token)
+  .idempotent(true)
+  .json(usage_request)?;
+
+  transport.send(request).await?.json()
+}
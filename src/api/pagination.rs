@@ -0,0 +1,656 @@
+use crate::api::{HttpTransport, ThingSpaceClient};
+use crate::models::timestamp::timestamp_millis;
+use crate::models::{
+  AccountDeviceListRequest, AccountDeviceListResponse, ConnectionEvent, ConnectionHistoryRequest,
+  ConnectionHistoryResponse, Device, DeviceGroupInformation, DeviceGroupPageRequest, DeviceIdList,
+  DeviceUsageRequest, DeviceUsageResponse, Error, ProvisioningEvent, ProvisioningHistoryRequest,
+  ProvisioningHistoryResponse, UsageRecord,
+};
+use futures_util::Stream;
+use iso8601::DateTime;
+use std::future::Future;
+
+/// One page of results from a paginated ThingSpace endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct Page<I> {
+  /// The items on this page.
+  pub items: Vec<I>,
+  /// `true` if ThingSpace has more items after this page.
+  pub has_more: bool,
+}
+
+impl From<AccountDeviceListResponse> for Page<Device> {
+  fn from(response: AccountDeviceListResponse) -> Page<Device> {
+    Page {
+      items: response.devices,
+      has_more: response.has_more_data,
+    }
+  }
+}
+
+impl From<DeviceGroupInformation> for Page<DeviceIdList> {
+  fn from(group: DeviceGroupInformation) -> Page<DeviceIdList> {
+    Page {
+      items: group.devices,
+      has_more: group.has_more_data,
+    }
+  }
+}
+
+impl From<DeviceUsageResponse> for Page<UsageRecord> {
+  fn from(response: DeviceUsageResponse) -> Page<UsageRecord> {
+    Page {
+      items: response.usage_history,
+      has_more: response.has_more_data,
+    }
+  }
+}
+
+impl From<ConnectionHistoryResponse> for Page<ConnectionEvent> {
+  fn from(response: ConnectionHistoryResponse) -> Page<ConnectionEvent> {
+    Page {
+      items: response.connection_history,
+      has_more: response.has_more_data,
+    }
+  }
+}
+
+impl From<ProvisioningHistoryResponse> for Page<ProvisioningEvent> {
+  fn from(response: ProvisioningHistoryResponse) -> Page<ProvisioningEvent> {
+    Page {
+      items: response.device_provisioning_history,
+      has_more: response.has_more_data,
+    }
+  }
+}
+
+/// Requests the pages of a paginated ThingSpace endpoint, one at a time.
+pub trait PageSource {
+  /// The type of item on each page.
+  type Item;
+
+  /// Requests the page that follows the `seen` items already received.
+  fn fetch(&mut self, seen: usize) -> impl Future<Output = Result<Page<Self::Item>, Error>>;
+}
+
+/// Yields the items of a paginated endpoint one by one, requesting the next page only when the
+/// previous one has been used up.
+///
+/// After an error is returned, or once `max_total` items have been returned, [`Paginated::next`]
+/// returns `None`.
+#[derive(Debug)]
+pub struct Paginated<S: PageSource> {
+  source: S,
+  buffer: std::vec::IntoIter<S::Item>,
+  seen: usize,
+  returned: usize,
+  max_total: Option<usize>,
+  has_more: bool,
+}
+
+impl<S: PageSource> Paginated<S> {
+  /// Creates a [`Paginated`] that requests its pages from `source`.
+  #[must_use]
+  pub fn new(source: S) -> Paginated<S> {
+    Paginated {
+      source,
+      buffer: Vec::new().into_iter(),
+      seen: 0,
+      returned: 0,
+      max_total: None,
+      has_more: true,
+    }
+  }
+
+  /// Stops after `max_total` items have been returned.
+  #[must_use]
+  pub fn max_total(mut self, max_total: usize) -> Paginated<S> {
+    self.max_total = Some(max_total);
+    self
+  }
+
+  /// Returns the next item, requesting the next page first if needed.
+  /// Returns `None` once every item has been returned or after an error.
+  pub async fn next(&mut self) -> Option<Result<S::Item, Error>> {
+    if self.max_total.is_some_and(|max| self.returned >= max) {
+      return None;
+    }
+
+    loop {
+      if let Some(item) = self.buffer.next() {
+        self.returned += 1;
+        return Some(Ok(item));
+      }
+
+      if !self.has_more {
+        return None;
+      }
+
+      match self.source.fetch(self.seen).await {
+        Ok(page) => {
+          // An empty page would request the same page again forever.
+          self.has_more = page.has_more && !page.items.is_empty();
+          self.seen += page.items.len();
+          self.buffer = page.items.into_iter();
+        }
+        Err(e) => {
+          self.has_more = false;
+          return Some(Err(e));
+        }
+      }
+    }
+  }
+
+  /// Requests every remaining page and returns all items.
+  /// # Errors
+  /// Returns the first `Error` encountered.
+  pub async fn try_collect(mut self) -> Result<Vec<S::Item>, Error> {
+    let mut items = Vec::new();
+    while let Some(item) = self.next().await {
+      items.push(item?);
+    }
+
+    Ok(items)
+  }
+
+  /// Turns this into a [`Stream`].
+  pub fn into_stream(self) -> impl Stream<Item = Result<S::Item, Error>> {
+    futures_util::stream::unfold(self, |mut pages| async move {
+      let item = pages.next().await?;
+      Some((item, pages))
+    })
+  }
+}
+
+/// A [`PageSource`] over `/devices/actions/list`.
+///
+/// ThingSpace pages the device list with [`AccountDeviceListRequest::largest_device_id_seen`].
+/// The devices in the response do not carry the account-internal ID this cursor is named after,
+/// so it is advanced as an offset: each request sends the value the caller set, or `0`, plus the
+/// number of devices received since. Set it before creating the source to resume an earlier
+/// listing. The page size is [`AccountDeviceListRequest::max_number_of_devices`].
+#[derive(Debug)]
+pub struct DeviceListSource<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  request: AccountDeviceListRequest,
+  start: Option<i32>,
+}
+
+impl<'a, T: HttpTransport> DeviceListSource<'a, T> {
+  /// Creates a [`DeviceListSource`] that sends `request` with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    request: AccountDeviceListRequest,
+  ) -> DeviceListSource<'a, T> {
+    DeviceListSource {
+      client,
+      start: request.largest_device_id_seen,
+      request,
+    }
+  }
+}
+
+impl<T: HttpTransport> PageSource for DeviceListSource<'_, T> {
+  type Item = Device;
+
+  async fn fetch(&mut self, seen: usize) -> Result<Page<Device>, Error> {
+    self.request.largest_device_id_seen = cursor_after(self.start, seen);
+
+    Ok(self.client.devices_list(&mut self.request).await?.into())
+  }
+}
+
+/// The lazily paged list of devices returned by [`ThingSpaceClient::devices_stream`].
+pub type DeviceStream<'a, T> = Paginated<DeviceListSource<'a, T>>;
+
+/// A [`PageSource`] over the devices of a device group, `/groups/{account_name}/name/{group_name}`.
+///
+/// [`DeviceGroupPageRequest::largest_device_id_seen`] is advanced the same way as in
+/// [`DeviceListSource`]. The page size is [`DeviceGroupPageRequest::max_number_of_devices`].
+#[derive(Debug)]
+pub struct DeviceGroupSource<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  group_name: String,
+  page: DeviceGroupPageRequest,
+  start: Option<i32>,
+}
+
+impl<'a, T: HttpTransport> DeviceGroupSource<'a, T> {
+  /// Creates a [`DeviceGroupSource`] that requests the devices of `group_name` with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    group_name: impl Into<String>,
+    page: DeviceGroupPageRequest,
+  ) -> DeviceGroupSource<'a, T> {
+    DeviceGroupSource {
+      client,
+      group_name: group_name.into(),
+      start: page.largest_device_id_seen,
+      page,
+    }
+  }
+}
+
+impl<T: HttpTransport> PageSource for DeviceGroupSource<'_, T> {
+  type Item = DeviceIdList;
+
+  async fn fetch(&mut self, seen: usize) -> Result<Page<DeviceIdList>, Error> {
+    self.page.largest_device_id_seen = cursor_after(self.start, seen);
+
+    Ok(
+      self
+        .client
+        .device_group(&self.group_name, &self.page)
+        .await?
+        .into(),
+    )
+  }
+}
+
+/// The lazily paged devices of a device group returned by
+/// [`ThingSpaceClient::device_group_stream`].
+pub type DeviceGroupStream<'a, T> = Paginated<DeviceGroupSource<'a, T>>;
+
+/// Returns the `largestDeviceIdSeen` cursor for the page after `seen` devices, counting from
+/// the caller's `start`.
+fn cursor_after(start: Option<i32>, seen: usize) -> Option<i32> {
+  match i32::try_from(seen).unwrap_or(i32::MAX) {
+    0 => start,
+    seen => Some(start.unwrap_or_default().saturating_add(seen)),
+  }
+}
+
+/// Tracks the time of the last event received from a history endpoint.
+///
+/// The history endpoints page by moving `earliest` to the time of the last event received. Since
+/// `earliest` is inclusive, the next page starts with the events at that time again, so they are
+/// counted here and dropped from the next page.
+#[derive(Debug, Default)]
+struct HistoryCursor {
+  /// The time of the last event received, in milliseconds since the Unix epoch.
+  last: Option<i64>,
+  /// How many events at `last` have been received.
+  at_last: usize,
+}
+
+impl HistoryCursor {
+  /// Drops the events of `page` that were already received and returns the `earliest` time for
+  /// the next page. Sets `page.has_more` to `false` if the page holds no new events with a time,
+  /// since the next page would be the same.
+  fn advance<I>(
+    &mut self,
+    page: &mut Page<I>,
+    occurred_at: impl Fn(&I) -> Option<&DateTime>,
+  ) -> Option<DateTime> {
+    let mut skipped = 0;
+    page.items.retain(|item| {
+      let Some(millis) = occurred_at(item).and_then(timestamp_millis) else {
+        return true;
+      };
+      match self.last {
+        Some(last) if millis < last => false,
+        Some(last) if millis == last && skipped < self.at_last => {
+          skipped += 1;
+          false
+        }
+        _ => true,
+      }
+    });
+
+    let newest = page
+      .items
+      .iter()
+      .filter_map(|item| occurred_at(item).and_then(|t| Some((timestamp_millis(t)?, *t))))
+      .max_by_key(|(millis, _)| *millis);
+
+    let Some((millis, timestamp)) = newest else {
+      page.has_more = false;
+      return None;
+    };
+
+    let at_newest = page
+      .items
+      .iter()
+      .filter(|item| occurred_at(item).and_then(timestamp_millis) == Some(millis))
+      .count();
+    if self.last == Some(millis) {
+      self.at_last += at_newest;
+    } else {
+      self.last = Some(millis);
+      self.at_last = at_newest;
+    }
+
+    Some(timestamp)
+  }
+}
+
+/// A [`PageSource`] over `/devices/connections/actions/listHistory`.
+///
+/// After each page, [`ConnectionHistoryRequest::earliest`] is moved to the time of the last event
+/// received, and the events at that time are not returned again. Paging stops once a page holds
+/// no new events.
+#[derive(Debug)]
+pub struct ConnectionHistorySource<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  request: ConnectionHistoryRequest,
+  cursor: HistoryCursor,
+}
+
+impl<'a, T: HttpTransport> ConnectionHistorySource<'a, T> {
+  /// Creates a [`ConnectionHistorySource`] that sends `request` with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    request: ConnectionHistoryRequest,
+  ) -> ConnectionHistorySource<'a, T> {
+    ConnectionHistorySource {
+      client,
+      request,
+      cursor: HistoryCursor::default(),
+    }
+  }
+}
+
+impl<T: HttpTransport> PageSource for ConnectionHistorySource<'_, T> {
+  type Item = ConnectionEvent;
+
+  async fn fetch(&mut self, _seen: usize) -> Result<Page<ConnectionEvent>, Error> {
+    let mut page: Page<ConnectionEvent> =
+      self.client.connection_history(&self.request).await?.into();
+
+    if let Some(earliest) = self.cursor.advance(&mut page, |event: &ConnectionEvent| {
+      event.occurred_at.as_ref()
+    }) {
+      self.request.earliest = earliest;
+    }
+
+    Ok(page)
+  }
+}
+
+/// The lazily paged connection history returned by
+/// [`ThingSpaceClient::connection_history_stream`].
+pub type ConnectionHistoryStream<'a, T> = Paginated<ConnectionHistorySource<'a, T>>;
+
+/// A [`PageSource`] over `/devices/history/actions/list`.
+///
+/// After each page, [`ProvisioningHistoryRequest::earliest`] is moved to the time of the last
+/// event received, and the events at that time are not returned again. Paging stops once a page
+/// holds no new events.
+#[derive(Debug)]
+pub struct ProvisioningHistorySource<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  request: ProvisioningHistoryRequest,
+  cursor: HistoryCursor,
+}
+
+impl<'a, T: HttpTransport> ProvisioningHistorySource<'a, T> {
+  /// Creates a [`ProvisioningHistorySource`] that sends `request` with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    request: ProvisioningHistoryRequest,
+  ) -> ProvisioningHistorySource<'a, T> {
+    ProvisioningHistorySource {
+      client,
+      request,
+      cursor: HistoryCursor::default(),
+    }
+  }
+}
+
+impl<T: HttpTransport> PageSource for ProvisioningHistorySource<'_, T> {
+  type Item = ProvisioningEvent;
+
+  async fn fetch(&mut self, _seen: usize) -> Result<Page<ProvisioningEvent>, Error> {
+    let mut page: Page<ProvisioningEvent> = self
+      .client
+      .provisioning_history(&self.request)
+      .await?
+      .into();
+
+    if let Some(earliest) = self.cursor.advance(&mut page, |event: &ProvisioningEvent| {
+      event.occurred_at.as_ref()
+    }) {
+      self.request.earliest = earliest;
+    }
+
+    Ok(page)
+  }
+}
+
+/// The lazily paged provisioning history returned by
+/// [`ThingSpaceClient::provisioning_history_stream`].
+pub type ProvisioningHistoryStream<'a, T> = Paginated<ProvisioningHistorySource<'a, T>>;
+
+/// A [`PageSource`] over `/devices/usage/actions/list`.
+///
+/// After each page, [`DeviceUsageRequest::earliest`] is moved to the time of the last record
+/// received, and the records at that time are not returned again. Paging stops once a page holds
+/// no new records.
+#[derive(Debug)]
+pub struct DeviceUsageSource<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  request: DeviceUsageRequest,
+  cursor: HistoryCursor,
+}
+
+impl<'a, T: HttpTransport> DeviceUsageSource<'a, T> {
+  /// Creates a [`DeviceUsageSource`] that sends `request` with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    request: DeviceUsageRequest,
+  ) -> DeviceUsageSource<'a, T> {
+    DeviceUsageSource {
+      client,
+      request,
+      cursor: HistoryCursor::default(),
+    }
+  }
+}
+
+impl<T: HttpTransport> PageSource for DeviceUsageSource<'_, T> {
+  type Item = UsageRecord;
+
+  async fn fetch(&mut self, _seen: usize) -> Result<Page<UsageRecord>, Error> {
+    let mut page: Page<UsageRecord> = self.client.device_usage(&self.request).await?.into();
+
+    if let Some(earliest) = self.cursor.advance(&mut page, |record: &UsageRecord| {
+      record.event_date_time.as_ref()
+    }) {
+      self.request.earliest = earliest;
+    }
+
+    Ok(page)
+  }
+}
+
+/// The lazily paged usage history returned by [`ThingSpaceClient::device_usage_stream`].
+pub type DeviceUsageStream<'a, T> = Paginated<DeviceUsageSource<'a, T>>;
+
+#[cfg(test)]
+mod tests {
+  use super::{HistoryCursor, Page, PageSource, Paginated, cursor_after};
+  use crate::models::Error;
+  use crate::models::timestamp::parse_timestamp;
+  use iso8601::DateTime;
+  use std::collections::VecDeque;
+
+  /// Returns the queued pages in order and records the `seen` of every request.
+  struct FakeSource {
+    pages: VecDeque<Result<Page<u32>, Error>>,
+    seen: Vec<usize>,
+  }
+
+  impl FakeSource {
+    fn new(pages: impl IntoIterator<Item = (Vec<u32>, bool)>) -> FakeSource {
+      FakeSource {
+        pages: pages
+          .into_iter()
+          .map(|(items, has_more)| Ok(Page { items, has_more }))
+          .collect(),
+        seen: Vec::new(),
+      }
+    }
+  }
+
+  impl PageSource for FakeSource {
+    type Item = u32;
+
+    async fn fetch(&mut self, seen: usize) -> Result<Page<u32>, Error> {
+      self.seen.push(seen);
+      self
+        .pages
+        .pop_front()
+        .expect("requested a page after the last one")
+    }
+  }
+
+  #[tokio::test]
+  async fn stops_after_the_last_page() {
+    let mut pages = Paginated::new(FakeSource::new([
+      (vec![1, 2], true),
+      (vec![3], true),
+      (vec![4], false),
+    ]));
+
+    let mut items = Vec::new();
+    while let Some(item) = pages.next().await {
+      items.push(item.unwrap());
+    }
+    assert_eq!(items, [1, 2, 3, 4]);
+    assert_eq!(pages.source.seen, [0, 2, 3]);
+    assert!(pages.next().await.is_none());
+  }
+
+  #[tokio::test]
+  async fn stops_on_an_empty_page() {
+    let pages = Paginated::new(FakeSource::new([(vec![1], true), (Vec::new(), true)]));
+    assert_eq!(pages.try_collect().await.unwrap(), [1]);
+  }
+
+  #[tokio::test]
+  async fn stops_at_max_total_without_requesting_more() {
+    let mut pages =
+      Paginated::new(FakeSource::new([(vec![1, 2], true), (vec![3, 4], true)])).max_total(3);
+
+    let mut items = Vec::new();
+    while let Some(item) = pages.next().await {
+      items.push(item.unwrap());
+    }
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(pages.source.seen, [0, 2]);
+  }
+
+  #[tokio::test]
+  async fn stops_after_an_error() {
+    let mut source = FakeSource::new([(vec![1], true)]);
+    source.pages.push_back(Err(Error::Serde(
+      serde_json::from_str::<u8>("").unwrap_err(),
+    )));
+    let mut pages = Paginated::new(source);
+
+    assert_eq!(pages.next().await.unwrap().unwrap(), 1);
+    assert!(pages.next().await.unwrap().is_err());
+    assert!(pages.next().await.is_none());
+  }
+
+  #[test]
+  fn device_cursor_counts_from_the_callers_start() {
+    assert_eq!(cursor_after(None, 0), None);
+    assert_eq!(cursor_after(Some(500), 0), Some(500));
+    assert_eq!(cursor_after(None, 100), Some(100));
+    assert_eq!(cursor_after(Some(500), 100), Some(600));
+    assert_eq!(cursor_after(Some(i32::MAX - 1), usize::MAX), Some(i32::MAX));
+  }
+
+  /// A history event: an id and the time it occurred.
+  type Event = (u32, Option<DateTime>);
+
+  fn event(id: u32, occurred_at: &str) -> Event {
+    (id, parse_timestamp(occurred_at))
+  }
+
+  fn advance(cursor: &mut HistoryCursor, items: Vec<Event>) -> (Vec<u32>, bool, Option<DateTime>) {
+    let mut page = Page {
+      items,
+      has_more: true,
+    };
+    let earliest = cursor.advance(&mut page, |(_, occurred_at): &Event| occurred_at.as_ref());
+    let ids = page.items.iter().map(|(id, _)| *id).collect();
+    (ids, page.has_more, earliest)
+  }
+
+  #[test]
+  fn history_cursor_drops_repeated_boundary_events() {
+    let mut cursor = HistoryCursor::default();
+
+    let (ids, has_more, earliest) = advance(
+      &mut cursor,
+      vec![
+        event(1, "2024-05-01T10:00:00Z"),
+        event(2, "2024-05-01T10:05:00Z"),
+        event(3, "2024-05-01T10:05:00Z"),
+      ],
+    );
+    assert_eq!(ids, [1, 2, 3]);
+    assert!(has_more);
+    assert_eq!(earliest, parse_timestamp("2024-05-01T10:05:00Z"));
+
+    // `earliest` is inclusive, so events 2 and 3 come back first. The same instant in another
+    // offset is still the same time.
+    let (ids, has_more, earliest) = advance(
+      &mut cursor,
+      vec![
+        event(2, "2024-05-01T10:05:00Z"),
+        event(3, "2024-05-01T12:05:00+02:00"),
+        event(4, "2024-05-01T10:05:00Z"),
+        event(5, "2024-05-01T10:09:00Z"),
+      ],
+    );
+    assert_eq!(ids, [4, 5]);
+    assert!(has_more);
+    assert_eq!(earliest, parse_timestamp("2024-05-01T10:09:00Z"));
+  }
+
+  #[test]
+  fn history_cursor_counts_events_across_pages_at_the_same_time() {
+    let mut cursor = HistoryCursor::default();
+    let at = "2024-05-01T10:00:00Z";
+
+    assert_eq!(
+      advance(&mut cursor, vec![event(1, at), event(2, at)]).0,
+      [1, 2]
+    );
+    // The next page repeats both and adds a third event at the same time.
+    let (ids, has_more, _) = advance(&mut cursor, vec![event(1, at), event(2, at), event(3, at)]);
+    assert_eq!(ids, [3]);
+    assert!(has_more);
+    let (ids, has_more, _) = advance(&mut cursor, vec![event(1, at), event(2, at), event(3, at)]);
+    assert!(ids.is_empty());
+    assert!(!has_more);
+  }
+
+  #[test]
+  fn history_cursor_stops_without_new_timestamps() {
+    let mut cursor = HistoryCursor::default();
+    advance(&mut cursor, vec![event(1, "2024-05-01T10:00:00Z")]);
+
+    // Older events are dropped, events without a timestamp are kept, but with no new
+    // timestamped event the next page would be the same.
+    let (ids, has_more, earliest) = advance(
+      &mut cursor,
+      vec![
+        event(0, "2024-05-01T09:00:00Z"),
+        event(1, "2024-05-01T10:00:00Z"),
+        (7, None),
+      ],
+    );
+    assert_eq!(ids, [7]);
+    assert!(!has_more);
+    assert_eq!(earliest, None);
+  }
+}
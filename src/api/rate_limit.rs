@@ -0,0 +1,350 @@
+use crate::api::{HttpRequest, HttpResponse, HttpTransport};
+use crate::models::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use strum::{Display, EnumString};
+use web_time::Instant;
+
+/// Groups of ThingSpace endpoints that share a rate limit.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
+pub enum EndpointGroup {
+  /// OAuth2 access token and M2M session token requests.
+  Auth,
+  /// Device Management endpoints, such as `/devices/actions/list`.
+  Devices,
+  /// NIDD messages sent with `send_nidd`.
+  Nidd,
+  /// SMS endpoints, such as `send_sms`.
+  Sms,
+  /// Registered Callback Listener endpoints.
+  Callbacks,
+  /// Requests that do not belong to any other group.
+  #[default]
+  Other,
+}
+
+/// A number of requests allowed per time window. Up to `requests` can be sent in a burst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rate {
+  /// Requests allowed per `per`.
+  pub requests: u32,
+  /// The length of the time window.
+  pub per: Duration,
+}
+
+impl Rate {
+  /// `requests` per second.
+  #[must_use]
+  pub fn per_second(requests: u32) -> Rate {
+    Rate {
+      requests,
+      per: Duration::from_secs(1),
+    }
+  }
+
+  /// `requests` per minute.
+  #[must_use]
+  pub fn per_minute(requests: u32) -> Rate {
+    Rate {
+      requests,
+      per: Duration::from_secs(60),
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Bucket {
+  tokens: f64,
+  updated: Instant,
+}
+
+/// A token bucket rate limiter keyed by account name and [`EndpointGroup`].
+///
+/// Each account and endpoint group gets its own bucket that refills at the configured [`Rate`].
+/// Groups without a configured rate are not limited. A caller that finds its bucket empty
+/// reserves the next token and waits until it is due, so waiting callers are served in order.
+///
+/// The limiter only holds its lock while updating a bucket, never across an `.await`, so it works
+/// on tokio as well as in a single-threaded wasm or Workers runtime. Share one limiter between
+/// every [`ThingSpaceClient`](crate::api::ThingSpaceClient) that uses the same account.
+///
+/// Only requests sent through a [`RateLimitTransport`] are limited, which includes every request
+/// of a [`ThingSpaceClient`](crate::api::ThingSpaceClient). The free API functions, such as
+/// `send_nidd` and `devices_list`, only retry and are not rate limited.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use thingspace_sdk::api::{EndpointGroup, Rate, RateLimiter};
+///
+/// let limiter = RateLimiter::default()
+///   .with_limit(EndpointGroup::Nidd, Rate::per_second(2))
+///   .with_account_limit("0000123456-00001", EndpointGroup::Nidd, Rate::per_second(1));
+///
+/// assert_eq!(limiter.reserve("0000123456-00001", EndpointGroup::Nidd), Duration::ZERO);
+/// assert!(limiter.reserve("0000123456-00001", EndpointGroup::Nidd) > Duration::ZERO);
+/// assert_eq!(limiter.reserve("0000123456-00001", EndpointGroup::Devices), Duration::ZERO);
+/// ```
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+  limits: HashMap<EndpointGroup, Rate>,
+  account_limits: HashMap<(String, EndpointGroup), Rate>,
+  buckets: Mutex<HashMap<(String, EndpointGroup), Bucket>>,
+}
+
+impl RateLimiter {
+  /// Limits `group` to `rate` for every account without its own limit.
+  #[must_use]
+  pub fn with_limit(mut self, group: EndpointGroup, rate: Rate) -> RateLimiter {
+    self.limits.insert(group, rate);
+    self
+  }
+
+  /// Limits `group` to `rate` for `account_name`.
+  #[must_use]
+  pub fn with_account_limit(
+    mut self,
+    account_name: &str,
+    group: EndpointGroup,
+    rate: Rate,
+  ) -> RateLimiter {
+    self
+      .account_limits
+      .insert((account_name.to_string(), group), rate);
+    self
+  }
+
+  /// The [`Rate`] that applies to `group` for `account_name`, if any.
+  #[must_use]
+  pub fn rate(&self, account_name: &str, group: EndpointGroup) -> Option<Rate> {
+    self
+      .account_limits
+      .get(&(account_name.to_string(), group))
+      .or_else(|| self.limits.get(&group))
+      .copied()
+  }
+
+  /// Takes a token from the bucket for `account_name` and `group` and returns how long the caller
+  /// has to wait before sending its request. Returns `Duration::ZERO` if a token was available or
+  /// the group is not limited.
+  pub fn reserve(&self, account_name: &str, group: EndpointGroup) -> Duration {
+    self.reserve_at(account_name, group, Instant::now())
+  }
+
+  /// [`RateLimiter::reserve`] at the time `now`.
+  fn reserve_at(&self, account_name: &str, group: EndpointGroup, now: Instant) -> Duration {
+    let Some(rate) = self.rate(account_name, group) else {
+      return Duration::ZERO;
+    };
+    if rate.requests == 0 || rate.per.is_zero() {
+      return Duration::ZERO;
+    }
+
+    let capacity = f64::from(rate.requests);
+    let per_second = capacity / rate.per.as_secs_f64();
+
+    let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+    let bucket = buckets
+      .entry((account_name.to_string(), group))
+      .or_insert(Bucket {
+        tokens: capacity,
+        updated: now,
+      });
+
+    let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+    bucket.tokens = (bucket.tokens + elapsed * per_second).min(capacity);
+    bucket.updated = now;
+    bucket.tokens -= 1.0;
+
+    if bucket.tokens >= 0.0 {
+      Duration::ZERO
+    } else {
+      Duration::from_secs_f64(-bucket.tokens / per_second)
+    }
+  }
+}
+
+/// A [`HttpTransport`] that waits on a [`RateLimiter`] before every request it sends through
+/// another transport.
+#[derive(Clone, Debug)]
+pub struct RateLimitTransport<T> {
+  transport: T,
+  limiter: Arc<RateLimiter>,
+  account_name: String,
+}
+
+impl<T> RateLimitTransport<T> {
+  /// Creates a [`RateLimitTransport`] that sends requests for `account_name` with `transport`.
+  #[must_use]
+  pub fn new(transport: T, limiter: Arc<RateLimiter>, account_name: &str) -> RateLimitTransport<T> {
+    RateLimitTransport {
+      transport,
+      limiter,
+      account_name: account_name.to_string(),
+    }
+  }
+
+  /// The wrapped transport.
+  #[must_use]
+  pub fn transport(&self) -> &T {
+    &self.transport
+  }
+
+  /// The [`RateLimiter`] every request waits on.
+  #[must_use]
+  pub fn limiter(&self) -> &Arc<RateLimiter> {
+    &self.limiter
+  }
+
+  /// Replaces the [`RateLimiter`].
+  pub fn set_limiter(&mut self, limiter: Arc<RateLimiter>) {
+    self.limiter = limiter;
+  }
+}
+
+impl<T: HttpTransport> HttpTransport for RateLimitTransport<T> {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+    let wait = self.limiter.reserve(&self.account_name, request.group);
+    if !wait.is_zero() {
+      self.transport.sleep(wait).await;
+    }
+
+    self.transport.send(request).await
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    self.transport.sleep(duration).await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{EndpointGroup, Rate, RateLimiter};
+  use std::time::Duration;
+  use web_time::Instant;
+
+  const ACCOUNT: &str = "0000123456-00001";
+
+  #[test]
+  fn allows_a_burst_then_reserves_the_next_token() {
+    let limiter = RateLimiter::default().with_limit(EndpointGroup::Sms, Rate::per_second(2));
+    let now = Instant::now();
+    let reserve = || limiter.reserve_at(ACCOUNT, EndpointGroup::Sms, now);
+
+    assert_eq!(reserve(), Duration::ZERO);
+    assert_eq!(reserve(), Duration::ZERO);
+    assert_eq!(reserve(), Duration::from_millis(500));
+    // The next caller waits behind the reserved token.
+    assert_eq!(reserve(), Duration::from_secs(1));
+  }
+
+  #[test]
+  fn refills_over_time() {
+    let limiter = RateLimiter::default().with_limit(EndpointGroup::Nidd, Rate::per_second(1));
+    let start = Instant::now();
+    let reserve = |after: u64| {
+      limiter.reserve_at(
+        ACCOUNT,
+        EndpointGroup::Nidd,
+        start + Duration::from_millis(after),
+      )
+    };
+
+    assert_eq!(reserve(0), Duration::ZERO);
+    assert_eq!(reserve(250), Duration::from_millis(750));
+    // Pays back the reserved token and refills the bucket.
+    assert_eq!(reserve(2_000), Duration::ZERO);
+    assert_eq!(reserve(2_500), Duration::from_millis(500));
+  }
+
+  #[test]
+  fn refill_is_capped_at_the_burst_size() {
+    let limiter = RateLimiter::default().with_limit(EndpointGroup::Devices, Rate::per_second(2));
+    let start = Instant::now();
+    let later = start + Duration::from_secs(60);
+
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Devices, start),
+      Duration::ZERO
+    );
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Devices, later),
+      Duration::ZERO
+    );
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Devices, later),
+      Duration::ZERO
+    );
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Devices, later),
+      Duration::from_millis(500)
+    );
+  }
+
+  #[test]
+  fn an_earlier_time_does_not_refill() {
+    let limiter = RateLimiter::default().with_limit(EndpointGroup::Sms, Rate::per_second(1));
+    let start = Instant::now() + Duration::from_secs(1);
+
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Sms, start),
+      Duration::ZERO
+    );
+    let earlier = start - Duration::from_millis(500);
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Sms, earlier),
+      Duration::from_secs(1)
+    );
+  }
+
+  #[test]
+  fn buckets_are_kept_per_account_and_group() {
+    let limiter = RateLimiter::default()
+      .with_limit(EndpointGroup::Sms, Rate::per_minute(1))
+      .with_account_limit(ACCOUNT, EndpointGroup::Sms, Rate::per_minute(2));
+
+    assert_eq!(
+      limiter.rate(ACCOUNT, EndpointGroup::Sms),
+      Some(Rate::per_minute(2))
+    );
+    assert_eq!(
+      limiter.rate("other", EndpointGroup::Sms),
+      Some(Rate::per_minute(1))
+    );
+    assert_eq!(limiter.rate(ACCOUNT, EndpointGroup::Auth), None);
+
+    let now = Instant::now();
+    assert_eq!(
+      limiter.reserve_at("other", EndpointGroup::Sms, now),
+      Duration::ZERO
+    );
+    assert_eq!(
+      limiter.reserve_at("other", EndpointGroup::Sms, now),
+      Duration::from_secs(60)
+    );
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Sms, now),
+      Duration::ZERO
+    );
+    assert_eq!(
+      limiter.reserve_at(ACCOUNT, EndpointGroup::Sms, now),
+      Duration::ZERO
+    );
+    for _ in 0..10 {
+      assert_eq!(
+        limiter.reserve_at(ACCOUNT, EndpointGroup::Auth, now),
+        Duration::ZERO
+      );
+    }
+  }
+
+  #[test]
+  fn zero_rates_are_not_limited() {
+    let limiter = RateLimiter::default().with_limit(EndpointGroup::Sms, Rate::per_second(0));
+    for _ in 0..10 {
+      assert_eq!(limiter.reserve(ACCOUNT, EndpointGroup::Sms), Duration::ZERO);
+    }
+  }
+}
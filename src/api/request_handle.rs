@@ -0,0 +1,193 @@
+use crate::api::{HttpTransport, ThingSpaceClient};
+use crate::models::{Error, RequestStatusResponse};
+use std::fmt;
+use std::time::Duration;
+use web_time::Instant;
+
+/// The delay between two status requests made by [`RequestHandle::wait`].
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long [`RequestHandle::wait`] polls before it gives up.
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The result of [`RequestHandle::wait`].
+#[derive(Clone, Debug)]
+pub enum WaitOutcome {
+  /// The request finished with a terminal status, see
+  /// [`RequestStatus::is_terminal`](crate::models::RequestStatus::is_terminal).
+  Finished(RequestStatusResponse),
+  /// The timeout passed while the request was still being processed. Holds the last status.
+  TimedOut(RequestStatusResponse),
+}
+
+/// An asynchronous ThingSpace request, such as a NIDD message or a device action, that can be
+/// polled with `/accounts/{account_name}/requests/{request_id}` until it finishes.
+///
+/// ThingSpace also reports the outcome on the callback listener of the request's service; the
+/// handle is for callers that do not run a listener or missed the callback.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::{HttpTransport, ThingSpaceClient, WaitOutcome};
+/// use thingspace_sdk::models::{Error, NiddMessage, RequestStatus};
+///
+/// async fn send_and_wait<T: HttpTransport>(
+///   client: &ThingSpaceClient<T>,
+///   msg: &mut NiddMessage,
+/// ) -> Result<bool, Error> {
+///   let handle = client.send_nidd(msg).await?;
+///   println!("Sent NIDD message {}", handle.request_id());
+///
+///   match handle.wait().await? {
+///     WaitOutcome::Finished(response) => Ok(response.status == RequestStatus::Success),
+///     WaitOutcome::TimedOut(response) => {
+///       println!("Still {} after the timeout", response.status);
+///       Ok(false)
+///     }
+///   }
+/// }
+/// ```
+pub struct RequestHandle<'a, T: HttpTransport> {
+  client: &'a ThingSpaceClient<T>,
+  request_id: String,
+  poll_interval: Duration,
+  timeout: Duration,
+}
+
+impl<'a, T: HttpTransport> RequestHandle<'a, T> {
+  /// Creates a [`RequestHandle`] for the request `request_id` that polls with `client`.
+  #[must_use]
+  pub fn new(
+    client: &'a ThingSpaceClient<T>,
+    request_id: impl Into<String>,
+  ) -> RequestHandle<'a, T> {
+    RequestHandle {
+      client,
+      request_id: request_id.into(),
+      poll_interval: DEFAULT_POLL_INTERVAL,
+      timeout: DEFAULT_WAIT_TIMEOUT,
+    }
+  }
+
+  /// Sets the delay between two status requests made by [`RequestHandle::wait`].
+  #[must_use]
+  pub fn poll_interval(mut self, poll_interval: Duration) -> RequestHandle<'a, T> {
+    self.poll_interval = poll_interval;
+    self
+  }
+
+  /// Sets how long [`RequestHandle::wait`] polls before it gives up.
+  #[must_use]
+  pub fn timeout(mut self, timeout: Duration) -> RequestHandle<'a, T> {
+    self.timeout = timeout;
+    self
+  }
+
+  /// The id of the request.
+  #[must_use]
+  pub fn request_id(&self) -> &str {
+    &self.request_id
+  }
+
+  /// Requests the current status of the request once.
+  /// # Errors
+  /// Returns the ThingSpace `Error` or the `Error` from the transport.
+  pub async fn status(&self) -> Result<RequestStatusResponse, Error> {
+    self.client.request_status(&self.request_id).await
+  }
+
+  /// Polls the status of the request until it is terminal, see
+  /// [`RequestStatus::is_terminal`](crate::models::RequestStatus::is_terminal), or the timeout
+  /// has passed. On timeout [`WaitOutcome::TimedOut`] holds the last, not yet terminal, status.
+  /// # Errors
+  /// Returns the first ThingSpace `Error` or `Error` from the transport.
+  pub async fn wait(&self) -> Result<WaitOutcome, Error> {
+    let start = Instant::now();
+    loop {
+      let response = self.status().await?;
+      if response.status.is_terminal() {
+        return Ok(WaitOutcome::Finished(response));
+      }
+      if start.elapsed() + self.poll_interval > self.timeout {
+        return Ok(WaitOutcome::TimedOut(response));
+      }
+
+      self.client.transport().sleep(self.poll_interval).await;
+    }
+  }
+}
+
+impl<T: HttpTransport> fmt::Debug for RequestHandle<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("RequestHandle")
+      .field("request_id", &self.request_id)
+      .field("poll_interval", &self.poll_interval)
+      .field("timeout", &self.timeout)
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::WaitOutcome;
+  use crate::api::mock_transport::{MockTransport, client};
+  use crate::models::RequestStatus;
+  use std::time::Duration;
+
+  /// Answers status requests with `statuses` in order, repeating the last one.
+  fn statuses(statuses: &'static [&'static str]) -> MockTransport {
+    let mut next = 0;
+    MockTransport::new(move |_| {
+      let status = statuses[next.min(statuses.len() - 1)];
+      next += 1;
+      let body = serde_json::json!({ "requestId": "r-1", "status": status });
+      (200, body.to_string())
+    })
+  }
+
+  #[tokio::test]
+  async fn wait_stops_on_a_terminal_status() {
+    let client = client(statuses(&["Pending", "InProgress", "SUCCESS"])).await;
+    let handle = client
+      .request_handle("r-1")
+      .poll_interval(Duration::from_millis(5))
+      .timeout(Duration::from_secs(3600));
+
+    let WaitOutcome::Finished(response) = handle.wait().await.unwrap() else {
+      panic!("expected WaitOutcome::Finished");
+    };
+    assert_eq!(response.status, RequestStatus::Success);
+    assert_eq!(client.transport().requests_to("/requests/r-1").len(), 3);
+    assert_eq!(client.transport().sleeps(), [Duration::from_millis(5); 2]);
+  }
+
+  #[tokio::test]
+  async fn wait_times_out_on_a_non_terminal_status() {
+    let client = client(statuses(&["Queued"])).await;
+    let handle = client
+      .request_handle("r-1")
+      .poll_interval(Duration::from_secs(5))
+      .timeout(Duration::from_secs(1));
+
+    let WaitOutcome::TimedOut(response) = handle.wait().await.unwrap() else {
+      panic!("expected WaitOutcome::TimedOut");
+    };
+    assert_eq!(response.status, RequestStatus::Queued);
+    assert_eq!(client.transport().requests_to("/requests/r-1").len(), 1);
+    assert!(client.transport().sleeps().is_empty());
+  }
+
+  #[tokio::test]
+  async fn unknown_statuses_are_not_terminal() {
+    let client = client(statuses(&["Throttled"])).await;
+    let handle = client.request_handle("r-1").timeout(Duration::ZERO);
+
+    let WaitOutcome::TimedOut(response) = handle.wait().await.unwrap() else {
+      panic!("expected WaitOutcome::TimedOut");
+    };
+    assert_eq!(
+      response.status,
+      RequestStatus::Unknown("Throttled".to_string())
+    );
+  }
+}
@@ -48,3 +48,19 @@ pub fn encode_login_field<'a>(
 
   Ok(dst)
 }
+
+/// Appends `segment` to `url`, percent-encoding everything but unreserved characters so names
+/// with spaces or slashes stay a single path segment.
+pub fn push_path_segment(url: &mut String, segment: &str) {
+  const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+  for byte in segment.bytes() {
+    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+      url.push(char::from(byte));
+    } else {
+      url.push('%');
+      url.push(char::from(HEX[usize::from(byte >> 4)]));
+      url.push(char::from(HEX[usize::from(byte & 0x0F)]));
+    }
+  }
+}
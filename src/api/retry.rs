@@ -0,0 +1,456 @@
+use crate::api::{HttpRequest, HttpResponse, HttpTransport};
+use crate::models::{Error, ErrorKind, ResponseError};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+/// Decides which failed requests are sent again and how long to wait in between.
+///
+/// A response is retried if its status is one of `retry_statuses`, or if it is a 4xx or 5xx
+/// response whose [`ErrorKind`] is one of `retry_kinds`, e.g. a ThingSpace rate limit error code
+/// sent with status 400.
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at `max_delay`, minus a random
+/// share of up to `jitter` of it. If the response carries a `Retry-After` header with a number of
+/// seconds, that delay is used instead; if it is longer than `max_delay` the request is not retried.
+///
+/// Only idempotent requests are retried (see [`HttpRequest::idempotent`]). `send_nidd` is not
+/// idempotent, so it is only retried when `retry_non_idempotent` is set.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use thingspace_sdk::api::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///   max_attempts: 5,
+///   base_delay: Duration::from_millis(500),
+///   retry_non_idempotent: true,
+///   ..Default::default()
+/// };
+/// assert_eq!(policy.backoff(3), Duration::from_secs(2));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+  /// Total number of attempts, including the first. `1` disables retries.
+  pub max_attempts: u32,
+  /// Delay before the first retry.
+  pub base_delay: Duration,
+  /// Upper bound for any single delay.
+  pub max_delay: Duration,
+  /// Share of each delay, between `0.0` and `1.0`, that is randomly taken off so concurrent
+  /// callers do not retry in lockstep.
+  pub jitter: f64,
+  /// Response status codes that are retried.
+  pub retry_statuses: Vec<u16>,
+  /// Kinds of error responses that are retried whatever their status, see
+  /// [`ResponseError::kind`].
+  pub retry_kinds: Vec<ErrorKind>,
+  /// Retry when no response was received at all, e.g. on a connection reset.
+  pub retry_transport_errors: bool,
+  /// Retry requests that are not idempotent, such as `send_nidd`.
+  pub retry_non_idempotent: bool,
+  /// Wait for the delay given in a `Retry-After` response header.
+  pub honor_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> RetryPolicy {
+    RetryPolicy {
+      max_attempts: 3,
+      base_delay: Duration::from_millis(250),
+      max_delay: Duration::from_secs(10),
+      jitter: 0.5,
+      retry_statuses: vec![429, 502, 503, 504],
+      retry_kinds: vec![ErrorKind::RateLimited],
+      retry_transport_errors: true,
+      retry_non_idempotent: false,
+      honor_retry_after: true,
+    }
+  }
+}
+
+impl RetryPolicy {
+  /// A [`RetryPolicy`] that sends every request exactly once.
+  #[must_use]
+  pub fn none() -> RetryPolicy {
+    RetryPolicy {
+      max_attempts: 1,
+      ..Default::default()
+    }
+  }
+
+  /// The delay before retry number `retry` (starting at `1`), without jitter.
+  #[must_use]
+  pub fn backoff(&self, retry: u32) -> Duration {
+    let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+    self.base_delay.saturating_mul(factor).min(self.max_delay)
+  }
+
+  /// The delay before retry number `retry` (starting at `1`), with jitter applied.
+  #[must_use]
+  pub fn jittered_backoff(&self, retry: u32) -> Duration {
+    let backoff = self.backoff(retry);
+    backoff.saturating_sub(backoff.mul_f64(self.jitter.clamp(0.0, 1.0) * random_fraction()))
+  }
+
+  /// Returns how long to wait before sending `request` again after attempt number `attempt`
+  /// ended with `result`, or `None` if it should not be retried.
+  #[must_use]
+  pub fn retry_delay(
+    &self,
+    request: &HttpRequest,
+    attempt: u32,
+    result: &Result<HttpResponse, Error>,
+  ) -> Option<Duration> {
+    if attempt >= self.max_attempts || !(request.idempotent || self.retry_non_idempotent) {
+      return None;
+    }
+
+    match result {
+      Ok(response) if self.is_retryable(response) => match self.retry_after(response) {
+        Some(delay) if delay > self.max_delay => None,
+        Some(delay) => Some(delay),
+        None => Some(self.jittered_backoff(attempt)),
+      },
+      Err(_) if self.retry_transport_errors => Some(self.jittered_backoff(attempt)),
+      _ => None,
+    }
+  }
+
+  /// Returns `true` if the status or the [`ErrorKind`] of `response` is retried.
+  fn is_retryable(&self, response: &HttpResponse) -> bool {
+    if self.retry_statuses.contains(&response.status) {
+      return true;
+    }
+    if !response.is_error() || self.retry_kinds.is_empty() {
+      return false;
+    }
+
+    let error = ResponseError::new(response.status, &response.headers, &response.body);
+    self.retry_kinds.contains(&error.kind())
+  }
+
+  /// Reads a `Retry-After` header given in seconds. HTTP dates are ignored.
+  fn retry_after(&self, response: &HttpResponse) -> Option<Duration> {
+    if !self.honor_retry_after {
+      return None;
+    }
+
+    let seconds = response.header("Retry-After")?.trim().parse::<u64>().ok()?;
+    Some(Duration::from_secs(seconds))
+  }
+}
+
+/// A [`HttpTransport`] that sends requests through another transport and retries them according
+/// to a [`RetryPolicy`].
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::{HttpTransport, RetryPolicy, RetryTransport};
+///
+/// fn with_retries<T: HttpTransport>(transport: T) -> RetryTransport<T> {
+///   RetryTransport::new(transport, RetryPolicy::default())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RetryTransport<T> {
+  transport: T,
+  policy: RetryPolicy,
+}
+
+impl<T> RetryTransport<T> {
+  /// Creates a [`RetryTransport`] that sends requests with `transport`.
+  #[must_use]
+  pub fn new(transport: T, policy: RetryPolicy) -> RetryTransport<T> {
+    RetryTransport { transport, policy }
+  }
+
+  /// The wrapped transport.
+  #[must_use]
+  pub fn transport(&self) -> &T {
+    &self.transport
+  }
+
+  /// The wrapped transport, mutably.
+  #[must_use]
+  pub fn transport_mut(&mut self) -> &mut T {
+    &mut self.transport
+  }
+
+  /// The [`RetryPolicy`] used for every request.
+  #[must_use]
+  pub fn policy(&self) -> &RetryPolicy {
+    &self.policy
+  }
+
+  /// Replaces the [`RetryPolicy`].
+  pub fn set_policy(&mut self, policy: RetryPolicy) {
+    self.policy = policy;
+  }
+}
+
+impl<T: HttpTransport> HttpTransport for RetryTransport<T> {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+    let mut attempt = 1;
+    loop {
+      let result = self.transport.send(request.clone()).await;
+      let Some(delay) = self.policy.retry_delay(&request, attempt, &result) else {
+        return result;
+      };
+
+      self.transport.sleep(delay).await;
+      attempt += 1;
+    }
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    self.transport.sleep(duration).await;
+  }
+}
+
+/// A random number in `0.0..1.0`. Jitter does not need a strong source of randomness, so this is
+/// a SplitMix64 step over the current time and a call counter.
+fn random_fraction() -> f64 {
+  static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+  let nanos = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.subsec_nanos())
+    .unwrap_or_default();
+  let mut z = u64::from(nanos).wrapping_add(
+    COUNTER
+      .fetch_add(1, Ordering::Relaxed)
+      .wrapping_mul(0x9E37_79B9_7F4A_7C15),
+  );
+  z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  z ^= z >> 31;
+
+  #[allow(clippy::cast_precision_loss)]
+  let fraction = (z >> 11) as f64 / (1u64 << 53) as f64;
+  fraction
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{RetryPolicy, RetryTransport};
+  use crate::api::{HttpRequest, HttpResponse, HttpTransport, Method};
+  use crate::models::{Error, ErrorKind};
+  use std::sync::Mutex;
+  use std::time::Duration;
+
+  fn policy() -> RetryPolicy {
+    RetryPolicy {
+      max_attempts: 5,
+      base_delay: Duration::from_millis(100),
+      max_delay: Duration::from_secs(1),
+      jitter: 0.0,
+      ..Default::default()
+    }
+  }
+
+  fn get() -> HttpRequest {
+    HttpRequest::new(Method::Get, "https://example.com".to_string())
+  }
+
+  fn response(status: u16, retry_after: Option<&str>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse {
+      status,
+      headers: retry_after
+        .map(|value| vec![("retry-after".to_string(), value.to_string())])
+        .unwrap_or_default(),
+      body: Vec::new(),
+    })
+  }
+
+  fn thingspace_error(status: u16, code: &str) -> Result<HttpResponse, Error> {
+    let body = serde_json::json!({ "errorCode": code, "errorMessage": "" });
+    Ok(HttpResponse {
+      status,
+      headers: Vec::new(),
+      body: body.to_string().into_bytes(),
+    })
+  }
+
+  fn transport_error() -> Result<HttpResponse, Error> {
+    Err(Error::Serde(serde_json::from_str::<u8>("").unwrap_err()))
+  }
+
+  #[test]
+  fn backoff_doubles_up_to_max_delay() {
+    let policy = policy();
+    let delays: Vec<_> = (1..=5).map(|retry| policy.backoff(retry)).collect();
+    assert_eq!(
+      delays,
+      [100, 200, 400, 800, 1000].map(Duration::from_millis)
+    );
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+  }
+
+  #[test]
+  fn jitter_only_shortens_the_delay() {
+    let policy = RetryPolicy {
+      jitter: 0.5,
+      ..policy()
+    };
+    for _ in 0..100 {
+      let delay = policy.jittered_backoff(3);
+      assert!(delay <= Duration::from_millis(400));
+      assert!(delay >= Duration::from_millis(200));
+    }
+  }
+
+  #[test]
+  fn retries_listed_statuses_and_transport_errors() {
+    let policy = policy();
+    let request = get();
+    assert_eq!(
+      policy.retry_delay(&request, 2, &response(503, None)),
+      Some(Duration::from_millis(200))
+    );
+    assert_eq!(
+      policy.retry_delay(&request, 1, &transport_error()),
+      Some(Duration::from_millis(100))
+    );
+    assert_eq!(policy.retry_delay(&request, 1, &response(400, None)), None);
+    assert_eq!(policy.retry_delay(&request, 1, &response(200, None)), None);
+
+    let policy = RetryPolicy {
+      retry_transport_errors: false,
+      ..policy
+    };
+    assert_eq!(policy.retry_delay(&request, 1, &transport_error()), None);
+  }
+
+  #[test]
+  fn retries_listed_error_kinds_whatever_the_status() {
+    let policy = policy();
+    let rate_limited = thingspace_error(400, "REQUEST_FAILED.RateLimitExceeded");
+    assert_eq!(
+      policy.retry_delay(&get(), 1, &rate_limited),
+      Some(Duration::from_millis(100))
+    );
+    let invalid = thingspace_error(400, "REQUEST_FAILED.ServicePlan.Invalid");
+    assert_eq!(policy.retry_delay(&get(), 1, &invalid), None);
+    let auth = thingspace_error(401, "UnifiedWebService.REQUEST_FAILED.SessionToken.Expired");
+    assert_eq!(policy.retry_delay(&get(), 1, &auth), None);
+
+    let policy = RetryPolicy {
+      retry_kinds: Vec::new(),
+      ..policy
+    };
+    assert_eq!(policy.retry_delay(&get(), 1, &rate_limited), None);
+
+    let policy = RetryPolicy {
+      retry_kinds: vec![ErrorKind::Server],
+      ..policy
+    };
+    assert!(
+      policy
+        .retry_delay(&get(), 1, &response(500, None))
+        .is_some()
+    );
+    assert_eq!(policy.retry_delay(&get(), 1, &response(200, None)), None);
+  }
+
+  #[test]
+  fn stops_after_max_attempts() {
+    let policy = policy();
+    assert!(
+      policy
+        .retry_delay(&get(), 4, &response(503, None))
+        .is_some()
+    );
+    assert_eq!(policy.retry_delay(&get(), 5, &response(503, None)), None);
+    assert_eq!(
+      RetryPolicy::none().retry_delay(&get(), 1, &response(503, None)),
+      None
+    );
+  }
+
+  #[test]
+  fn honors_retry_after_seconds() {
+    let policy = policy();
+    assert_eq!(
+      policy.retry_delay(&get(), 1, &response(429, Some(" 1 "))),
+      Some(Duration::from_secs(1))
+    );
+    // Longer than `max_delay`, so the request is not retried at all.
+    assert_eq!(
+      policy.retry_delay(&get(), 1, &response(429, Some("2"))),
+      None
+    );
+    // HTTP dates are ignored in favor of the backoff.
+    assert_eq!(
+      policy.retry_delay(
+        &get(),
+        1,
+        &response(429, Some("Wed, 21 Oct 2015 07:28:00 GMT"))
+      ),
+      Some(Duration::from_millis(100))
+    );
+
+    let policy = RetryPolicy {
+      honor_retry_after: false,
+      ..policy
+    };
+    assert_eq!(
+      policy.retry_delay(&get(), 1, &response(429, Some("2"))),
+      Some(Duration::from_millis(100))
+    );
+  }
+
+  #[test]
+  fn only_retries_non_idempotent_requests_when_allowed() {
+    let post = HttpRequest::new(Method::Post, "https://example.com".to_string());
+    assert!(!post.idempotent);
+    assert_eq!(policy().retry_delay(&post, 1, &response(503, None)), None);
+    assert!(
+      policy()
+        .retry_delay(&post.clone().idempotent(true), 1, &response(503, None))
+        .is_some()
+    );
+
+    let policy = RetryPolicy {
+      retry_non_idempotent: true,
+      ..policy()
+    };
+    assert!(policy.retry_delay(&post, 1, &response(503, None)).is_some());
+  }
+
+  /// Answers with the queued statuses in order and records every sleep.
+  #[derive(Default)]
+  struct FakeTransport {
+    statuses: Mutex<Vec<u16>>,
+    sleeps: Mutex<Vec<Duration>>,
+  }
+
+  impl HttpTransport for FakeTransport {
+    async fn send(&self, _request: HttpRequest) -> Result<HttpResponse, Error> {
+      let status = self.statuses.lock().unwrap().remove(0);
+      response(status, None)
+    }
+
+    async fn sleep(&self, duration: Duration) {
+      self.sleeps.lock().unwrap().push(duration);
+    }
+  }
+
+  #[tokio::test]
+  async fn transport_sends_again_until_success() {
+    let fake = FakeTransport {
+      statuses: Mutex::new(vec![503, 502, 200]),
+      ..Default::default()
+    };
+    let transport = RetryTransport::new(fake, policy());
+
+    let response = transport.send(get()).await.unwrap();
+    assert_eq!(response.status, 200);
+    assert_eq!(
+      *transport.transport().sleeps.lock().unwrap(),
+      [Duration::from_millis(100), Duration::from_millis(200)]
+    );
+  }
+}
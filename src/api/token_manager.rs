@@ -0,0 +1,357 @@
+use crate::models::{Error, LoginResponse, Session};
+use futures_util::lock::Mutex;
+use std::future::Future;
+use std::time::Duration;
+use web_time::Instant;
+
+/// Tokens are refreshed when they are within this margin of expiring.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// A struct containing a valid OAuth2 access token and M2M session token pair.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuthTokens {
+  /// The OAuth2 access token.
+  pub access_token: String,
+  /// The M2M session token, sent as the `VZ-M2M-Token` header.
+  pub session_token: String,
+}
+
+#[derive(Debug)]
+struct Token {
+  value: String,
+  issued_at: Instant,
+  last_used: Instant,
+  ttl: Duration,
+}
+
+impl Token {
+  fn new(value: String, expires_in: i32, now: Instant) -> Token {
+    Token {
+      value,
+      issued_at: now,
+      last_used: now,
+      ttl: Duration::from_secs(u64::try_from(expires_in).unwrap_or_default()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct TokenState {
+  access: Option<Token>,
+  session: Option<Token>,
+}
+
+impl TokenState {
+  /// The OAuth2 access token expires `expires_in` seconds after it was issued.
+  fn access_is_fresh(&self, now: Instant, margin: Duration) -> bool {
+    self
+      .access
+      .as_ref()
+      .is_some_and(|t| now + margin < t.issued_at + t.ttl)
+  }
+
+  /// The M2M session token expires after `expires_in` seconds of inactivity.
+  fn session_is_fresh(&self, now: Instant, margin: Duration) -> bool {
+    self
+      .session
+      .as_ref()
+      .is_some_and(|t| now + margin < t.last_used + t.ttl)
+  }
+}
+
+/// Keeps track of when the OAuth2 access token and M2M session token were issued and last used,
+/// and refreshes them before they expire.
+///
+/// The token state is guarded by an async mutex that is held while a refresh is in flight, so
+/// concurrent callers wait on a single login instead of each starting their own.
+///
+/// # Example
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use thingspace_sdk::api::TokenManager;
+/// use thingspace_sdk::models::{LoginResponse, Session};
+///
+/// #[tokio::main]
+/// async fn main() {
+///   let manager = TokenManager::default();
+///   let logins = AtomicUsize::new(0);
+///
+///   let get_tokens = || {
+///     manager.tokens(
+///       || async {
+///         logins.fetch_add(1, Ordering::SeqCst);
+///         Ok(LoginResponse {
+///           access_token: "access".to_string(),
+///           expires_in: 7200,
+///           ..Default::default()
+///         })
+///       },
+///       |_access_token| async {
+///         Ok(Session {
+///           session_token: "session".to_string(),
+///           ..Default::default()
+///         })
+///       },
+///     )
+///   };
+///
+///   let (first, second) = tokio::join!(get_tokens(), get_tokens());
+///   assert_eq!(first.unwrap(), second.unwrap());
+///   assert_eq!(logins.load(Ordering::SeqCst), 1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TokenManager {
+  state: Mutex<TokenState>,
+  refresh_margin: Duration,
+}
+
+impl Default for TokenManager {
+  fn default() -> TokenManager {
+    TokenManager::new(DEFAULT_REFRESH_MARGIN)
+  }
+}
+
+impl TokenManager {
+  /// Creates an empty [`TokenManager`] that refreshes tokens `refresh_margin` before they expire.
+  #[must_use]
+  pub fn new(refresh_margin: Duration) -> TokenManager {
+    TokenManager {
+      state: Mutex::new(TokenState::default()),
+      refresh_margin,
+    }
+  }
+
+  /// Returns the current [`AuthTokens`], first calling `login` and/or `session` to replace any
+  /// token that is missing or about to expire. `session` is given the current access token.
+  ///
+  /// Both tokens are marked as used, which keeps the M2M session alive.
+  /// # Errors
+  /// Returns the `Error` from `login` or `session`; a token that was refreshed before the error is kept.
+  pub async fn tokens<L, LF, S, SF>(&self, login: L, session: S) -> Result<AuthTokens, Error>
+  where
+    L: FnOnce() -> LF,
+    LF: Future<Output = Result<LoginResponse, Error>>,
+    S: FnOnce(String) -> SF,
+    SF: Future<Output = Result<Session, Error>>,
+  {
+    let mut state = self.state.lock().await;
+
+    if !state.access_is_fresh(Instant::now(), self.refresh_margin) {
+      let response = login().await?;
+      state.access = Some(Token::new(
+        response.access_token,
+        response.expires_in,
+        Instant::now(),
+      ));
+    }
+
+    if !state.session_is_fresh(Instant::now(), self.refresh_margin) {
+      let access_token = state
+        .access
+        .as_ref()
+        .map(|t| t.value.clone())
+        .unwrap_or_default();
+      let response = session(access_token).await?;
+      state.session = Some(Token::new(
+        response.session_token,
+        response.expires_in,
+        Instant::now(),
+      ));
+    }
+
+    let now = Instant::now();
+    let mut tokens = AuthTokens::default();
+    if let Some(access) = state.access.as_mut() {
+      access.last_used = now;
+      tokens.access_token.clone_from(&access.value);
+    }
+    if let Some(session) = state.session.as_mut() {
+      session.last_used = now;
+      tokens.session_token.clone_from(&session.value);
+    }
+
+    Ok(tokens)
+  }
+
+  /// Discards the stored tokens if they are still the `rejected` ones, so the next call to
+  /// [`TokenManager::tokens`] logs in again.
+  ///
+  /// Tokens that were already replaced by a concurrent caller are kept, so a burst of requests
+  /// rejected with the same expired token only causes a single login.
+  pub async fn invalidate_rejected(&self, rejected: &AuthTokens) {
+    let mut state = self.state.lock().await;
+    if state
+      .access
+      .as_ref()
+      .is_some_and(|t| t.value == rejected.access_token)
+    {
+      state.access = None;
+    }
+    if state
+      .session
+      .as_ref()
+      .is_some_and(|t| t.value == rejected.session_token)
+    {
+      state.session = None;
+    }
+  }
+
+  /// Discards both tokens so the next call to [`TokenManager::tokens`] logs in again.
+  pub async fn invalidate(&self) {
+    let mut state = self.state.lock().await;
+    state.access = None;
+    state.session = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AuthTokens, Token, TokenManager, TokenState};
+  use crate::api::Endpoints;
+  use crate::api::mock_transport::{LOGIN_URL, M2M_URL, MockTransport};
+  use crate::api::operations::{get_access_token, get_session_token};
+  use crate::models::{Error, SessionRequestBody};
+  use std::time::Duration;
+  use web_time::Instant;
+
+  const MINUTE: Duration = Duration::from_secs(60);
+
+  /// Gets the tokens from `transport`, yielding before the access token request so concurrent
+  /// callers get a chance to run.
+  async fn tokens(manager: &TokenManager, transport: &MockTransport) -> Result<AuthTokens, Error> {
+    let endpoints = &Endpoints::new(M2M_URL, LOGIN_URL);
+    let cred = SessionRequestBody {
+      username: "user".to_string(),
+      password: "password".to_string(),
+    };
+
+    manager
+      .tokens(
+        || async {
+          tokio::task::yield_now().await;
+          get_access_token(transport, endpoints, "public", "private").await
+        },
+        |access_token| async move {
+          get_session_token(transport, endpoints, &cred, &access_token).await
+        },
+      )
+      .await
+  }
+
+  fn tokens_of(access: &str, session: &str) -> AuthTokens {
+    AuthTokens {
+      access_token: access.to_string(),
+      session_token: session.to_string(),
+    }
+  }
+
+  fn mock() -> MockTransport {
+    MockTransport::new(|_| (404, String::new()))
+  }
+
+  #[test]
+  fn access_expires_after_issue_and_session_after_last_use() {
+    let start = Instant::now();
+    let token = |ttl: Duration| Token {
+      value: String::new(),
+      issued_at: start,
+      last_used: start + 100 * MINUTE,
+      ttl,
+    };
+    let state = TokenState {
+      access: Some(token(120 * MINUTE)),
+      session: Some(token(20 * MINUTE)),
+    };
+
+    let at = |minutes: u32| start + minutes * MINUTE;
+    assert!(state.access_is_fresh(at(110), Duration::ZERO));
+    assert!(state.session_is_fresh(at(110), Duration::ZERO));
+    // Using the access token does not extend it, using the session token does.
+    assert!(!state.access_is_fresh(at(121), Duration::ZERO));
+    assert!(state.session_is_fresh(at(119), Duration::ZERO));
+    assert!(!state.session_is_fresh(at(121), Duration::ZERO));
+    // Tokens are refreshed `margin` before they expire.
+    assert!(!state.access_is_fresh(at(110), 10 * MINUTE));
+    assert!(!TokenState::default().access_is_fresh(start, Duration::ZERO));
+  }
+
+  #[tokio::test]
+  async fn reuses_fresh_tokens() {
+    let (manager, transport) = (TokenManager::default(), mock());
+
+    let first = tokens(&manager, &transport).await.unwrap();
+    let second = tokens(&manager, &transport).await.unwrap();
+    assert_eq!(first, tokens_of("access-1", "session-1"));
+    assert_eq!(second, first);
+    assert_eq!(transport.logins(), (1, 1));
+  }
+
+  #[tokio::test]
+  async fn refreshes_only_the_expired_token() {
+    let (manager, transport) = (TokenManager::default(), mock());
+    tokens(&manager, &transport).await.unwrap();
+
+    manager.state.lock().await.session.as_mut().unwrap().ttl = Duration::ZERO;
+    let refreshed = tokens(&manager, &transport).await.unwrap();
+    assert_eq!(refreshed, tokens_of("access-1", "session-2"));
+    assert_eq!(transport.logins(), (1, 2));
+    // The new session is started with the current access token.
+    let sessions = transport.requests_to("/session/login");
+    assert_eq!(
+      sessions[1].get_header("Authorization"),
+      Some("Bearer access-1")
+    );
+
+    manager.state.lock().await.access.as_mut().unwrap().ttl = Duration::ZERO;
+    let refreshed = tokens(&manager, &transport).await.unwrap();
+    assert_eq!(refreshed, tokens_of("access-2", "session-2"));
+    assert_eq!(transport.logins(), (2, 2));
+  }
+
+  #[tokio::test]
+  async fn concurrent_callers_share_one_login() {
+    let (manager, transport) = (TokenManager::default(), mock());
+
+    let (a, b, c) = tokio::join!(
+      tokens(&manager, &transport),
+      tokens(&manager, &transport),
+      tokens(&manager, &transport),
+    );
+    assert_eq!(a.unwrap(), tokens_of("access-1", "session-1"));
+    assert_eq!(b.unwrap(), tokens_of("access-1", "session-1"));
+    assert_eq!(c.unwrap(), tokens_of("access-1", "session-1"));
+    assert_eq!(transport.logins(), (1, 1));
+  }
+
+  #[tokio::test]
+  async fn invalidate_rejected_only_discards_the_rejected_tokens() {
+    let (manager, transport) = (TokenManager::default(), mock());
+    let current = tokens(&manager, &transport).await.unwrap();
+
+    // Tokens that were already replaced are kept.
+    manager
+      .invalidate_rejected(&tokens_of("access-0", "session-0"))
+      .await;
+    assert_eq!(tokens(&manager, &transport).await.unwrap(), current);
+    assert_eq!(transport.logins(), (1, 1));
+
+    // Only the session token was rejected.
+    manager
+      .invalidate_rejected(&tokens_of("access-0", "session-1"))
+      .await;
+    assert_eq!(
+      tokens(&manager, &transport).await.unwrap(),
+      tokens_of("access-1", "session-2")
+    );
+
+    manager
+      .invalidate_rejected(&tokens_of("access-1", "session-2"))
+      .await;
+    assert_eq!(
+      tokens(&manager, &transport).await.unwrap(),
+      tokens_of("access-2", "session-3")
+    );
+    assert_eq!(transport.logins(), (2, 3));
+  }
+}
@@ -0,0 +1,231 @@
+use crate::api::EndpointGroup;
+use crate::api::request_helpers::{SESSION_TOKEN_FIELD, oauth_field};
+use crate::models::{Error, ResponseError};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::time::Duration;
+use strum::{Display, EnumString};
+
+/// The HTTP methods used by the ThingSpace APIs.
+#[derive(Clone, Copy, Debug, Display, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Method {
+  Get,
+  Post,
+  Put,
+  Delete,
+}
+
+/// Whether a browser `fetch` sends cookies and HTTP authentication with a request. Only the
+/// `wasm` [`WebTransport`](crate::api::WebTransport) uses it, the other transports never send
+/// browser credentials.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, PartialEq, Eq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum CredentialsMode {
+  /// Never send credentials.
+  Omit,
+  /// Send credentials to the page's own origin only, the `fetch` default.
+  #[default]
+  SameOrigin,
+  /// Send credentials to every origin, including the cross-origin OAuth2 token endpoint.
+  Include,
+}
+
+/// A backend-agnostic HTTP request built by the ThingSpace endpoint functions.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+  /// The request method.
+  pub method: Method,
+  /// The absolute request URL.
+  pub url: String,
+  /// Header name and value pairs. A name appears at most once.
+  pub headers: Vec<(&'static str, String)>,
+  /// The request body, if any.
+  pub body: Option<String>,
+  /// Whether sending the request twice has the same effect as sending it once, which allows a
+  /// [`RetryTransport`](crate::api::RetryTransport) to retry it. Defaults to `true` for every
+  /// method except `POST`.
+  pub idempotent: bool,
+  /// The [`EndpointGroup`] whose rate limit applies to the request.
+  pub group: EndpointGroup,
+  /// The [`CredentialsMode`] of a browser `fetch`.
+  pub credentials: CredentialsMode,
+}
+
+impl HttpRequest {
+  /// Creates a [`HttpRequest`] that accepts `application/json` and has no body.
+  #[must_use]
+  pub fn new(method: Method, url: String) -> HttpRequest {
+    HttpRequest {
+      method,
+      url,
+      headers: vec![("Accept", "application/json".to_string())],
+      body: None,
+      idempotent: method != Method::Post,
+      group: EndpointGroup::default(),
+      credentials: CredentialsMode::default(),
+    }
+  }
+
+  /// Sets the [`EndpointGroup`] whose rate limit applies to the request.
+  #[must_use]
+  pub fn group(mut self, group: EndpointGroup) -> HttpRequest {
+    self.group = group;
+    self
+  }
+
+  /// Marks the request as safe, or not safe, to send more than once.
+  ///
+  /// ThingSpace uses `POST` for read-only queries such as the device list, usage and history
+  /// endpoints, since they take a JSON body. Those requests are marked idempotent so they are
+  /// retried like a `GET`.
+  #[must_use]
+  pub fn idempotent(mut self, idempotent: bool) -> HttpRequest {
+    self.idempotent = idempotent;
+    self
+  }
+
+  /// Sets the [`CredentialsMode`] of a browser `fetch`.
+  #[must_use]
+  pub fn credentials(mut self, credentials: CredentialsMode) -> HttpRequest {
+    self.credentials = credentials;
+    self
+  }
+
+  /// Sets the header `name` to `value`, replacing any previous value.
+  #[must_use]
+  pub fn header(mut self, name: &'static str, value: impl Into<String>) -> HttpRequest {
+    let value = value.into();
+    match self
+      .headers
+      .iter_mut()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name))
+    {
+      Some((_, v)) => *v = value,
+      None => self.headers.push((name, value)),
+    }
+
+    self
+  }
+
+  /// Sets the `Authorization: Bearer` header to the OAuth2 `access_token`.
+  #[must_use]
+  pub fn bearer(self, access_token: &str) -> HttpRequest {
+    self.header("Authorization", oauth_field(access_token))
+  }
+
+  /// Sets both the OAuth2 `access_token` and the M2M `session_token` headers.
+  #[must_use]
+  pub fn authorize(self, access_token: &str, session_token: &str) -> HttpRequest {
+    self
+      .bearer(access_token)
+      .header(SESSION_TOKEN_FIELD, session_token)
+  }
+
+  /// Sets the request body to `body`.
+  #[must_use]
+  pub fn body(mut self, body: impl Into<String>) -> HttpRequest {
+    self.body = Some(body.into());
+    self
+  }
+
+  /// Sets the request body to `body` serialized as JSON.
+  /// # Errors
+  /// Returns `Error::Serde` if `body` fails to serialize.
+  pub fn json<B: Serialize + ?Sized>(self, body: &B) -> Result<HttpRequest, Error> {
+    Ok(
+      self
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_string(body)?),
+    )
+  }
+
+  /// Returns the value of the header `name`, ignoring case.
+  #[must_use]
+  pub fn get_header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name))
+      .map(|(_, v)| v.as_str())
+  }
+}
+
+/// A backend-agnostic HTTP response returned by a [`HttpTransport`].
+#[derive(Clone, Debug, Default)]
+pub struct HttpResponse {
+  /// The HTTP status code.
+  pub status: u16,
+  /// Header name and value pairs.
+  pub headers: Vec<(String, String)>,
+  /// The raw response body.
+  pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+  /// Returns `true` for 4xx and 5xx status codes.
+  #[must_use]
+  pub fn is_error(&self) -> bool {
+    (400..600).contains(&self.status)
+  }
+
+  /// Returns the value of the header `name`, ignoring case.
+  #[must_use]
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name))
+      .map(|(_, v)| v.as_str())
+  }
+
+  /// Deserializes the response body as JSON.
+  /// # Errors
+  /// Returns `Error::Response` for 4xx and 5xx responses, otherwise `Error::Serde` if the body
+  /// fails to deserialize.
+  pub fn json<R: DeserializeOwned>(&self) -> Result<R, Error> {
+    if self.is_error() {
+      return Err(ResponseError::new(self.status, &self.headers, &self.body).into());
+    }
+
+    Ok(serde_json::from_slice(&self.body)?)
+  }
+}
+
+/// Sends a [`HttpRequest`] and returns the [`HttpResponse`].
+///
+/// Every ThingSpace endpoint is built on this trait, so the same endpoint code runs on
+/// [`reqwest`](https://docs.rs/reqwest), `worker::Fetch` and `web_sys` fetch. Implement it to
+/// use another HTTP client, a test double or a recording proxy.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use thingspace_sdk::api::{HttpRequest, HttpResponse, HttpTransport};
+/// use thingspace_sdk::models::Error;
+///
+/// /// A transport that answers every request with an empty callback listener list.
+/// struct NoListeners;
+///
+/// impl HttpTransport for NoListeners {
+///   async fn send(&self, _request: HttpRequest) -> Result<HttpResponse, Error> {
+///     Ok(HttpResponse {
+///       status: 200,
+///       body: b"[]".to_vec(),
+///       ..Default::default()
+///     })
+///   }
+///
+///   async fn sleep(&self, _duration: Duration) {}
+/// }
+/// ```
+pub trait HttpTransport {
+  /// Sends `request` and returns the response, including 4xx and 5xx responses.
+  /// # Errors
+  /// Returns an `Error` only if no response was received.
+  fn send(&self, request: HttpRequest) -> impl Future<Output = Result<HttpResponse, Error>>;
+
+  /// Waits for `duration` on the transport's runtime. Used to delay retries.
+  fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
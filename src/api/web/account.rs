@@ -0,0 +1,91 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  AccountInformation, AccountStatesResponse, Error, RequestStatusResponse, ServicePlan,
+};
+
+/// Returns the information and capabilities of a billing account as an
+/// [`AccountInformation`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn account_information(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<AccountInformation, Error> {
+  operations::account_information(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Returns the device states that are valid for a billing account as an
+/// [`AccountStatesResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn account_states(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<AccountStatesResponse, Error> {
+  operations::account_states(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Returns the [`ServicePlan`]s available to a billing account.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn service_plans(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<Vec<ServicePlan>, Error> {
+  operations::service_plans(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Returns the [`RequestStatusResponse`] of the asynchronous request `request_id`, e.g.
+/// [`NiddRequest::request_id`](crate::models::NiddRequest::request_id) or
+/// [`ActionResponse::request_id`](crate::models::ActionResponse::request_id).
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn request_status(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  request_id: &str,
+) -> Result<RequestStatusResponse, Error> {
+  operations::request_status(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    request_id,
+  )
+  .await
+}
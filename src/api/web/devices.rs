@@ -0,0 +1,76 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  AccountDeviceListRequest, AccountDeviceListResponse, Error, NiddMessage, NiddRequest,
+};
+
+/// Makes an API request for an Account Device List and returns a [`AccountDeviceListResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::api::{Endpoints, devices_list};
+/// use thingspace_sdk::models::{AccountDeviceListRequest, LoginResponse, Session};
+///
+/// async fn print_devices(account_name: &str, login: &LoginResponse, session: &Session) {
+///   let adl = AccountDeviceListRequest {
+///     account_name: Some(account_name.to_string()),
+///     ..Default::default()
+///   };
+///
+///   match devices_list(
+///     &Endpoints::default(),
+///     &login.access_token,
+///     &session.session_token,
+///     &adl,
+///   )
+///   .await
+///   {
+///     Ok(response) => {
+///       for device in response.devices {
+///         web_sys::console::log_1(&format!("{device:?}").into());
+///       }
+///     }
+///     Err(error) => {
+///       web_sys::console::error_1(&error.to_string().into());
+///     }
+///   }
+/// }
+/// ```
+pub async fn devices_list(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  adl: &AccountDeviceListRequest,
+) -> Result<AccountDeviceListResponse, Error> {
+  operations::devices_list(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    adl,
+  )
+  .await
+}
+
+/// Sends a NIDD message and returns the [`NiddRequest`] id.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn send_nidd(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  nidd_msg: &NiddMessage,
+) -> Result<NiddRequest, Error> {
+  operations::send_nidd(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    nidd_msg,
+  )
+  .await
+}
@@ -0,0 +1,70 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ActionResponse, Error, ReachabilityStatusRequest, ReachabilitySubscriptionRequest,
+};
+
+/// Subscribes to awake and sleep notifications for the SCEF devices in a
+/// [`ReachabilitySubscriptionRequest`] and returns the [`ActionResponse`] request id. The
+/// notifications are sent to the `DiagnosticsService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn subscribe_reachability(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  subscription: &ReachabilitySubscriptionRequest,
+) -> Result<ActionResponse, Error> {
+  operations::subscribe_reachability(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    subscription,
+  )
+  .await
+}
+
+/// Ends the reachability subscription of the SCEF devices in a [`ReachabilityStatusRequest`]
+/// and returns the [`ActionResponse`] request id.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn unsubscribe_reachability(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  devices: &ReachabilityStatusRequest,
+) -> Result<ActionResponse, Error> {
+  operations::unsubscribe_reachability(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    devices,
+  )
+  .await
+}
+
+/// Requests the current reachability of the SCEF devices in a [`ReachabilityStatusRequest`] and
+/// returns the [`ActionResponse`] request id. The status is sent to the `DiagnosticsService`
+/// callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn reachability_status(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  devices: &ReachabilityStatusRequest,
+) -> Result<ActionResponse, Error> {
+  operations::reachability_status(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    devices,
+  )
+  .await
+}
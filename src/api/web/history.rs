@@ -0,0 +1,48 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ConnectionHistoryRequest, ConnectionHistoryResponse, Error, ProvisioningHistoryRequest,
+  ProvisioningHistoryResponse,
+};
+
+/// Returns one page of the connection history of a single device as a
+/// [`ConnectionHistoryResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn connection_history(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ConnectionHistoryRequest,
+) -> Result<ConnectionHistoryResponse, Error> {
+  operations::connection_history(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    history_request,
+  )
+  .await
+}
+
+/// Returns one page of the provisioning history of a single device as a
+/// [`ProvisioningHistoryResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn provisioning_history(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  history_request: &ProvisioningHistoryRequest,
+) -> Result<ProvisioningHistoryResponse, Error> {
+  operations::provisioning_history(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    history_request,
+  )
+  .await
+}
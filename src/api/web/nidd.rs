@@ -0,0 +1,47 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ActionResponse, Error, NiddConfiguration, NiddConfigurationListRequest, NiddConfigurationRequest,
+};
+
+/// Sets up or changes the NIDD configuration of the devices in a [`NiddConfigurationRequest`]
+/// and returns the [`ActionResponse`] request id. The result is sent to the `NiddService`
+/// callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn configure_nidd(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  configuration: &NiddConfigurationRequest,
+) -> Result<ActionResponse, Error> {
+  operations::configure_nidd(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    configuration,
+  )
+  .await
+}
+
+/// Returns the NIDD configuration of the devices in a [`NiddConfigurationListRequest`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn nidd_configuration(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  list: &NiddConfigurationListRequest,
+) -> Result<Vec<NiddConfiguration>, Error> {
+  operations::nidd_configuration(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    list,
+  )
+  .await
+}
@@ -0,0 +1,67 @@
+use crate::api::web::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{CallbackListener, CallbackListenerResponse, Error};
+
+/// Registers a given URL as a callback listener for the given [`CallbackListener::service_name`] and account.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn register_callback_listener(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  cbl: &CallbackListener,
+) -> Result<CallbackListenerResponse, Error> {
+  operations::register_callback_listener(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    cbl,
+  )
+  .await
+}
+
+/// Removes a registered callback listener for the given [`CallbackListener::service_name`] and account.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn deregister_callback_listener(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+  service_name: &str,
+) -> Result<CallbackListenerResponse, Error> {
+  operations::deregister_callback_listener(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    service_name,
+  )
+  .await
+}
+
+/// Returns the name and endpoint URL of the callback listening services registered for a given account.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Js()` on a failed fetch request
+pub async fn list_callback_listeners(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<Vec<CallbackListener>, Error> {
+  operations::list_callback_listeners(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
@@ -0,0 +1,87 @@
+use crate::api::{
+  CredentialsMode, HttpRequest, HttpResponse, HttpTransport, RetryPolicy, RetryTransport,
+};
+use crate::models::Error;
+use std::time::Duration;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+  Headers, Request, RequestCredentials, RequestInit, RequestMode, Response, WorkerGlobalScope,
+};
+
+/// A [`HttpTransport`] that sends requests with the global `fetch` of a browser window or a
+/// web worker.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebTransport;
+
+/// The transport used by the web API functions: a [`WebTransport`] with the default [`RetryPolicy`].
+/// Requests are not rate limited, see [`RateLimiter`](crate::api::RateLimiter).
+pub(crate) fn default_transport() -> RetryTransport<WebTransport> {
+  RetryTransport::new(WebTransport, RetryPolicy::default())
+}
+
+impl HttpTransport for WebTransport {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+    let headers = Headers::new()?;
+    for (name, value) in &request.headers {
+      headers.set(name, value)?;
+    }
+
+    let request_init = RequestInit::new();
+    request_init.set_method(&request.method.to_string());
+    request_init.set_mode(RequestMode::Cors);
+    request_init.set_credentials(match request.credentials {
+      CredentialsMode::Omit => RequestCredentials::Omit,
+      CredentialsMode::SameOrigin => RequestCredentials::SameOrigin,
+      CredentialsMode::Include => RequestCredentials::Include,
+    });
+    request_init.set_headers(&headers);
+    if let Some(body) = &request.body {
+      request_init.set_body(&JsValue::from_str(body));
+    }
+
+    let fetch_request = Request::new_with_str_and_init(&request.url, &request_init)?;
+
+    let promise = match web_sys::window() {
+      Some(window) => window.fetch_with_request(&fetch_request),
+      None => js_sys::global()
+        .dyn_into::<WorkerGlobalScope>()
+        .map_err(JsValue::from)?
+        .fetch_with_request(&fetch_request),
+    };
+    let response: Response = JsFuture::from(promise).await?.dyn_into()?;
+
+    let mut response_headers = Vec::new();
+    if let Some(entries) = js_sys::try_iter(&response.headers())? {
+      for entry in entries {
+        let entry: js_sys::Array = entry?.dyn_into()?;
+        if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) {
+          response_headers.push((name, value));
+        }
+      }
+    }
+
+    let body = JsFuture::from(response.array_buffer()?).await?;
+
+    Ok(HttpResponse {
+      status: response.status(),
+      headers: response_headers,
+      body: js_sys::Uint8Array::new(&body).to_vec(),
+    })
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    let timeout = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+      let _ = match web_sys::window() {
+        Some(window) => {
+          window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout)
+        }
+        None => js_sys::global()
+          .unchecked_into::<WorkerGlobalScope>()
+          .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, timeout),
+      };
+    });
+    let _ = JsFuture::from(promise).await;
+  }
+}
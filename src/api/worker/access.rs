@@ -1,10 +1,6 @@
-use const_format::concatcp;
-use worker::{Fetch, Headers, Method, Request, RequestInit, Response, console_error};
-
-use crate::api::request_helpers::{
-  BASE64_BUF_SIZE, LOGIN_URL, M2M_REST_API_V1, encode_login_field, oauth_field,
-};
-use crate::models::{Error, SessionRequestBody};
+use crate::api::worker::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{Error, LoginResponse, Session, SessionRequestBody};
 
 /// Makes an API request for an OAuth2 access token and returns a [`LoginResponse`].
 /// # Panics
@@ -24,84 +20,24 @@ use crate::models::{Error, SessionRequestBody};
 /// assertions will fail and cause a panic.
 ///
 /// # Errors
-/// Returns HTTP response code or `thingspace_sdk::Error`.
+/// Returns `Error::Response` if the keys are rejected, otherwise `Error::Worker` on a failed
+/// Fetch request.
 pub async fn get_access_token(
+  endpoints: &Endpoints,
   public_key: &str,
   private_key: &str,
-) -> std::result::Result<Response, Error> {
-  let mut enc_buf = [0u8; BASE64_BUF_SIZE];
-  let auth = encode_login_field(public_key, private_key, &mut enc_buf)
-    .expect("Failed to encode login field");
-  let auth = std::str::from_utf8(auth)?.trim_end_matches('\0');
-
-  let headers = Headers::new();
-  headers.append("Accept", "application/json")?;
-  headers.append("Content-Type", "application/x-www-form-urlencoded")?;
-  headers.append("Authorization", auth)?;
-
-  let mut request_init = RequestInit::new();
-  request_init.with_method(Method::Post);
-  // request_init.set_mode(RequestMode::Cors);
-  // request_init.set_credentials(RequestCredentials::Include);
-
-  request_init.with_headers(headers);
-  request_init.with_body(Some(wasm_bindgen::JsValue::from_str(
-    "grant_type=client_credentials",
-  )));
-
-  let request = Request::new_with_init(LOGIN_URL, &request_init)?;
-
-  match Fetch::Request(request).send().await {
-    Ok(mut response) => {
-      let status = response.status_code();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::Credential(json));
-      }
-      Ok(response)
-    }
-    Err(e) => {
-      console_error!("{:?}", e);
-      Err(Error::Worker(e))
-    }
-  }
+) -> Result<LoginResponse, Error> {
+  operations::get_access_token(&default_transport(), endpoints, public_key, private_key).await
 }
 
 /// Makes an API request for a M2M session token and returns a [`Session`].
 /// # Errors
-/// Returns HTTP response code or `thingspace_sdk::Error`.
+/// Returns `Error::Response` on responses with status code 400..600, otherwise
+/// `Error::Worker` on a failed Fetch request.
 pub async fn get_session_token(
+  endpoints: &Endpoints,
   cred: &SessionRequestBody,
   access_token: &str,
-) -> std::result::Result<Response, Error> {
-  let headers = Headers::new();
-  headers.append("Accept", "application/json")?;
-  headers.append("Content-Type", "application/json")?;
-  headers.append("Authorization", &oauth_field(access_token))?;
-
-  let cred = serde_json::to_string(&cred)?;
-
-  let mut request_init = RequestInit::new();
-  request_init.with_method(Method::Post);
-
-  request_init.with_headers(headers);
-  request_init.with_body(Some(serde_wasm_bindgen::to_value(&cred)?));
-
-  let request =
-    Request::new_with_init(concatcp!(M2M_REST_API_V1, "/session/login"), &request_init)?;
-
-  match Fetch::Request(request).send().await {
-    Ok(mut response) => {
-      let status = response.status_code();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::Credential(json));
-      }
-      Ok(response)
-    }
-    Err(e) => {
-      console_error!("{:?}", e);
-      Err(Error::Worker(e))
-    }
-  }
+) -> Result<Session, Error> {
+  operations::get_session_token(&default_transport(), endpoints, cred, access_token).await
 }
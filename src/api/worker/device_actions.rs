@@ -0,0 +1,174 @@
+use crate::api::worker::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  ActionResponse, ActivateDevicesRequest, ChangeServicePlanRequest, DeactivateDevicesRequest,
+  DeleteDevicesRequest, DeviceStateRequest, Error, GoToStateRequest, UpdateCustomFieldsRequest,
+};
+
+/// Activates service for the devices in an [`ActivateDevicesRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn activate_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  activation: &ActivateDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  operations::activate_devices(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    activation,
+  )
+  .await
+}
+
+/// Deactivates service for the devices in a [`DeactivateDevicesRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn deactivate_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deactivation: &DeactivateDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  operations::deactivate_devices(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    deactivation,
+  )
+  .await
+}
+
+/// Suspends service for the devices in a [`DeviceStateRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn suspend_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  suspension: &DeviceStateRequest,
+) -> Result<ActionResponse, Error> {
+  operations::suspend_devices(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    suspension,
+  )
+  .await
+}
+
+/// Restores service for the suspended devices in a [`DeviceStateRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn restore_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  restoration: &DeviceStateRequest,
+) -> Result<ActionResponse, Error> {
+  operations::restore_devices(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    restoration,
+  )
+  .await
+}
+
+/// Deletes the deactivated devices in a [`DeleteDevicesRequest`] from the account and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn delete_devices(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  deletion: &DeleteDevicesRequest,
+) -> Result<ActionResponse, Error> {
+  operations::delete_devices(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    deletion,
+  )
+  .await
+}
+
+/// Changes the service plan of the devices in a [`ChangeServicePlanRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn change_service_plan(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  plan_change: &ChangeServicePlanRequest,
+) -> Result<ActionResponse, Error> {
+  operations::change_service_plan(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    plan_change,
+  )
+  .await
+}
+
+/// Moves the devices in a [`GoToStateRequest`] to a custom device state and returns the
+/// [`ActionResponse`] request id. The result is sent to the `StateService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn go_to_state(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  state_change: &GoToStateRequest,
+) -> Result<ActionResponse, Error> {
+  operations::go_to_state(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    state_change,
+  )
+  .await
+}
+
+/// Sets the custom field values of the devices in an [`UpdateCustomFieldsRequest`] and returns the
+/// [`ActionResponse`] request id. The result is sent to the `CarrierService` callback listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn update_custom_fields(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  custom_fields: &UpdateCustomFieldsRequest,
+) -> Result<ActionResponse, Error> {
+  operations::update_custom_fields(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    custom_fields,
+  )
+  .await
+}
@@ -1,19 +1,18 @@
-use crate::api::request_helpers::{M2M_REST_API_V1, SESSION_TOKEN_FIELD, oauth_field};
-use crate::models::{CallbackListener, Error};
-use const_format::concatcp;
-use worker::{Fetch, Headers, Method, Request, RequestInit, Response, console_error};
+use crate::api::worker::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{CallbackListener, CallbackListenerResponse, Error};
 
 /// Registers a given URL as a callback listener for the given [`CallbackListener::service_name`] and account.
 /// # Errors
-/// Returns `Error::ThingSpace()` on responses with status code 400..600
+/// Returns `Error::Response()` on responses with status code 400..600
 /// Returns `Error::Worker()` on a failed Fetch request
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use crate::cache;
-/// use thingspace_sdk::api::register_callback_listener;
+/// use thingspace_sdk::api::{Endpoints, error_response, register_callback_listener};
 /// use thingspace_sdk::models::CallbackListener;
-/// use worker::{Request, Response, RouteContext, console_error};
+/// use worker::{Request, Response, RouteContext};
 ///
 /// pub async fn create_listeners(mut req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
 ///   let ctype: Result<Option<String>, worker::Error> = req.headers().get("Content-Type");
@@ -41,14 +40,18 @@ use worker::{Fetch, Headers, Method, Request, RequestInit, Response, console_err
 ///     let aname = ctx.var("ACCOUNT_NAME")?;
 ///     let atoken = cache::access_token(&ctx).await?;
 ///     let stoken = cache::session_token(&ctx).await?;
-///     let vz_req = register_callback_listener(&aname.to_string(), &atoken, &stoken, &cbl).await;
+///     let vz_req = register_callback_listener(
+///       &Endpoints::default(),
+///       &aname.to_string(),
+///       &atoken,
+///       &stoken,
+///       &cbl,
+///     )
+///     .await;
 ///
 ///     match vz_req {
-///       Ok(resp) => Ok(resp),
-///       Err(e) => {
-///         console_error!("{:?}", e);
-///         Response::error(e.to_string(), 500)
-///       }
+///       Ok(resp) => Response::from_json(&resp),
+///       Err(e) => error_response(&e),
 ///     }
 ///   } else {
 ///     Response::error("Wrong 'Content-Type' header", 400)
@@ -56,57 +59,33 @@ use worker::{Fetch, Headers, Method, Request, RequestInit, Response, console_err
 /// }
 /// ```
 pub async fn register_callback_listener(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
   cbl: &CallbackListener,
-) -> std::result::Result<Response, Error> {
-  let mut uri = String::with_capacity(80);
-  uri.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  uri.push_str(account_name);
-
-  let headers = Headers::new();
-  headers.append("Accept", "application/json")?;
-  headers.append("Content-Type", "application/json")?;
-  headers.append("Authorization", &oauth_field(access_token))?;
-  headers.append(SESSION_TOKEN_FIELD, session_token)?;
-
-  let body = serde_json::to_string(cbl)?;
-
-  let mut request_init = RequestInit::new();
-  request_init.with_method(Method::Post);
-
-  request_init.with_headers(headers);
-  request_init.with_body(Some(serde_wasm_bindgen::to_value(&body)?));
-
-  let request = Request::new_with_init(&uri, &request_init)?;
-
-  match Fetch::Request(request).send().await {
-    Ok(mut response) => {
-      let status = response.status_code();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response)
-    }
-    Err(e) => {
-      console_error!("{:?}", e);
-      Err(Error::Worker(e))
-    }
-  }
+) -> Result<CallbackListenerResponse, Error> {
+  operations::register_callback_listener(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    cbl,
+  )
+  .await
 }
 
 /// Removes a registered callback listener for the given [`CallbackListener::service_name`] and account.
 /// # Errors
-/// Returns `Error::ThingSpace()` on responses with status code 400..600
+/// Returns `Error::Response()` on responses with status code 400..600
 /// Returns `Error::Worker()` on a failed Fetch request
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use crate::cache;
-/// use thingspace_sdk::api::deregister_callback_listener;
-/// use worker::{Request, Response, RouteContext, console_error};
+/// use thingspace_sdk::api::{Endpoints, deregister_callback_listener, error_response};
+/// use worker::{Request, Response, RouteContext};
 ///
 /// pub async fn delete_listeners(_req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
 ///   if let Some(sname) = ctx.param("name") {
@@ -114,14 +93,18 @@ pub async fn register_callback_listener(
 ///     let atoken = cache::access_token(&ctx).await?;
 ///     let stoken = cache::session_token(&ctx).await?;
 ///
-///     let vz_req = deregister_callback_listener(&aname.to_string(), &atoken, &stoken, sname).await;
+///     let vz_req = deregister_callback_listener(
+///       &Endpoints::default(),
+///       &aname.to_string(),
+///       &atoken,
+///       &stoken,
+///       sname,
+///     )
+///     .await;
 ///
 ///     match vz_req {
-///       Ok(resp) => Ok(resp),
-///       Err(e) => {
-///         console_error!("{:?}", e);
-///         Response::error(e.to_string(), 500)
-///       }
+///       Ok(resp) => Response::from_json(&resp),
+///       Err(e) => error_response(&e),
 ///     }
 ///   } else {
 ///     Response::error("Missing 'service_name' parameter in url", 400)
@@ -129,106 +112,60 @@ pub async fn register_callback_listener(
 /// }
 /// ```
 pub async fn deregister_callback_listener(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
   service_name: &str,
-) -> std::result::Result<Response, Error> {
-  let mut uri = String::with_capacity(128);
-  uri.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  uri.push_str(account_name);
-  uri.push_str("/name/");
-  uri.push_str(service_name);
-
-  let headers = Headers::new();
-  headers.append("Accept", "application/json")?;
-  headers.append("Content-Type", "application/json")?;
-  headers.append("Authorization", &oauth_field(access_token))?;
-  headers.append(SESSION_TOKEN_FIELD, session_token)?;
-
-  let mut request_init = RequestInit::new();
-  request_init.with_method(Method::Delete);
-
-  request_init.with_headers(headers);
-
-  let request = Request::new_with_init(&uri, &request_init)?;
-
-  match Fetch::Request(request).send().await {
-    Ok(mut response) => {
-      let status = response.status_code();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response)
-    }
-    Err(e) => {
-      console_error!("{:?}", e);
-      Err(Error::Worker(e))
-    }
-  }
+) -> Result<CallbackListenerResponse, Error> {
+  operations::deregister_callback_listener(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+    service_name,
+  )
+  .await
 }
 
 /// Returns the name and endpoint URL of the callback listening services registered for a given account.
 /// # Errors
-/// Returns `Error::ThingSpace()` on responses with status code 400..600
+/// Returns `Error::Response()` on responses with status code 400..600
 /// Returns `Error::Worker()` on a failed Fetch request
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// use crate::cache;
-/// use thingspace_sdk::api::list_callback_listeners;
-/// use worker::{Request, Response, RouteContext, console_error};
+/// use thingspace_sdk::api::{Endpoints, error_response, list_callback_listeners};
+/// use worker::{Request, Response, RouteContext};
 ///
 /// pub async fn list_listeners(_req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
 ///   let aname = ctx.var("ACCOUNT_NAME")?;
 ///   let atoken = cache::access_token(&ctx).await?;
 ///   let stoken = cache::session_token(&ctx).await?;
 ///
-///   let vz_req = list_callback_listeners(&aname.to_string(), &atoken, &stoken).await;
+///   let vz_req =
+///     list_callback_listeners(&Endpoints::default(), &aname.to_string(), &atoken, &stoken).await;
 ///
 ///   match vz_req {
-///     Ok(resp) => Ok(resp),
-///     Err(e) => {
-///       console_error!("{:?}", e);
-///       Response::error(e.to_string(), 500)
-///     }
+///     Ok(listeners) => Response::from_json(&listeners),
+///     Err(e) => error_response(&e),
 ///   }
 /// }
 /// ```
 pub async fn list_callback_listeners(
+  endpoints: &Endpoints,
   account_name: &str,
   access_token: &str,
   session_token: &str,
-) -> std::result::Result<Response, Error> {
-  let mut uri = String::with_capacity(80);
-  uri.push_str(concatcp!(M2M_REST_API_V1, "/callbacks/"));
-  uri.push_str(account_name);
-
-  let headers = Headers::new();
-  headers.append("Accept", "application/json")?;
-  headers.append("Authorization", &oauth_field(access_token))?;
-  headers.append(SESSION_TOKEN_FIELD, session_token)?;
-
-  let mut request_init = RequestInit::new();
-  request_init.with_method(Method::Get);
-
-  request_init.with_headers(headers);
-
-  let request = Request::new_with_init(&uri, &request_init)?;
-
-  match Fetch::Request(request).send().await {
-    Ok(mut response) => {
-      let status = response.status_code();
-      if (400..600).contains(&status) {
-        let json = response.json().await?;
-        return Err(Error::ThingSpace(json));
-      }
-      Ok(response)
-    }
-    Err(e) => {
-      console_error!("{:?}", e);
-      Err(Error::Worker(e))
-    }
-  }
+) -> Result<Vec<CallbackListener>, Error> {
+  operations::list_callback_listeners(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
 }
@@ -0,0 +1,50 @@
+use crate::models::Error;
+use worker::{Headers, Response};
+
+/// Turns an [`Error`] into a `worker::Response` so a Worker can proxy a failed ThingSpace call
+/// back to its own caller.
+///
+/// Error responses from ThingSpace are passed through with their status code, body and
+/// `Content-Type`. A rejected device ID becomes a `400`, and every other error a `500`, with the
+/// error's `Display` text as the body.
+///
+/// # Errors
+/// Returns `worker::Error` if the response fails to build.
+///
+/// # Example
+/// ```rust,ignore
+/// use crate::cache;
+/// use thingspace_sdk::api::{Endpoints, error_response, list_callback_listeners};
+/// use worker::{Request, Response, RouteContext};
+///
+/// pub async fn list_listeners(_req: Request, ctx: RouteContext<()>) -> worker::Result<Response> {
+///   let aname = ctx.var("ACCOUNT_NAME")?;
+///   let atoken = cache::access_token(&ctx).await?;
+///   let stoken = cache::session_token(&ctx).await?;
+///
+///   match list_callback_listeners(&Endpoints::default(), &aname.to_string(), &atoken, &stoken)
+///     .await
+///   {
+///     Ok(listeners) => Response::from_json(&listeners),
+///     Err(e) => error_response(&e),
+///   }
+/// }
+/// ```
+pub fn error_response(error: &Error) -> worker::Result<Response> {
+  match error {
+    Error::Response(e) => {
+      let headers = Headers::new();
+      if let Some(content_type) = e.header("content-type") {
+        headers.set("Content-Type", content_type)?;
+      }
+
+      Ok(
+        Response::ok(e.body.clone())?
+          .with_status(e.status)
+          .with_headers(headers),
+      )
+    }
+    e @ Error::InvalidDeviceId(_) => Response::error(e.to_string(), 400),
+    e => Response::error(e.to_string(), 500),
+  }
+}
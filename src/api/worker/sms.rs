@@ -0,0 +1,67 @@
+use crate::api::worker::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{ActionResponse, Error, SendSmsRequest, SmsHistoryResponse, SuccessResponse};
+
+/// Sends an SMS message to the devices in a [`SendSmsRequest`] and returns the
+/// [`ActionResponse`] request id. The `EnhancedConnectivityService` callback listener is notified
+/// when the network sends the message, and `SMSDeliveryConfirmation` when a device acknowledges it.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn send_sms(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  sms: &SendSmsRequest,
+) -> Result<ActionResponse, Error> {
+  operations::send_sms(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    sms,
+  )
+  .await
+}
+
+/// Returns the SMS messages that devices sent to a billing account and that are still queued
+/// as an [`SmsHistoryResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn sms_history(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<SmsHistoryResponse, Error> {
+  operations::sms_history(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
+
+/// Acknowledges the queued SMS messages of a billing account, so ThingSpace sends them and
+/// every new message to the `EnhancedConnectivityService` callback listener instead.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn start_sms_callbacks(
+  endpoints: &Endpoints,
+  account_name: &str,
+  access_token: &str,
+  session_token: &str,
+) -> Result<SuccessResponse, Error> {
+  operations::start_sms_callbacks(
+    &default_transport(),
+    endpoints,
+    account_name,
+    access_token,
+    session_token,
+  )
+  .await
+}
@@ -0,0 +1,53 @@
+use crate::api::{HttpRequest, HttpResponse, HttpTransport, Method, RetryPolicy, RetryTransport};
+use crate::models::Error;
+use std::time::Duration;
+use worker::{Delay, Fetch, Headers, Request, RequestInit, console_error};
+
+/// A [`HttpTransport`] that sends requests with `worker::Fetch`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WorkerTransport;
+
+/// The transport used by the worker API functions: a [`WorkerTransport`] with the default [`RetryPolicy`].
+/// Requests are not rate limited, see [`RateLimiter`](crate::api::RateLimiter).
+pub(crate) fn default_transport() -> RetryTransport<WorkerTransport> {
+  RetryTransport::new(WorkerTransport, RetryPolicy::default())
+}
+
+impl HttpTransport for WorkerTransport {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+    let headers = Headers::new();
+    for (name, value) in &request.headers {
+      headers.append(name, value)?;
+    }
+
+    let mut request_init = RequestInit::new();
+    request_init.with_method(match request.method {
+      Method::Get => worker::Method::Get,
+      Method::Post => worker::Method::Post,
+      Method::Put => worker::Method::Put,
+      Method::Delete => worker::Method::Delete,
+    });
+    request_init.with_headers(headers);
+    if let Some(body) = &request.body {
+      request_init.with_body(Some(wasm_bindgen::JsValue::from_str(body)));
+    }
+
+    let fetch_request = Request::new_with_init(&request.url, &request_init)?;
+
+    match Fetch::Request(fetch_request).send().await {
+      Ok(mut response) => Ok(HttpResponse {
+        status: response.status_code(),
+        headers: response.headers().entries().collect(),
+        body: response.bytes().await?,
+      }),
+      Err(e) => {
+        console_error!("{:?}", e);
+        Err(Error::Worker(e))
+      }
+    }
+  }
+
+  async fn sleep(&self, duration: Duration) {
+    Delay::from(duration).await;
+  }
+}
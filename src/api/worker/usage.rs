@@ -0,0 +1,47 @@
+use crate::api::worker::default_transport;
+use crate::api::{Endpoints, operations};
+use crate::models::{
+  AggregateUsageRequest, AggregateUsageResponse, DeviceUsageRequest, DeviceUsageResponse, Error,
+};
+
+/// Returns the usage history of a single device as a [`DeviceUsageResponse`].
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn device_usage(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &DeviceUsageRequest,
+) -> Result<DeviceUsageResponse, Error> {
+  operations::device_usage(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    usage_request,
+  )
+  .await
+}
+
+/// Requests the total usage of the devices in an [`AggregateUsageRequest`] and returns the
+/// [`AggregateUsageResponse`] transaction id. The result is sent to the `DeviceUsage` callback
+/// listener.
+/// # Errors
+/// Returns `Error::Response()` on responses with status code 400..600
+/// Returns `Error::Worker()` on a failed Fetch request
+pub async fn aggregate_usage(
+  endpoints: &Endpoints,
+  access_token: &str,
+  session_token: &str,
+  usage_request: &AggregateUsageRequest,
+) -> Result<AggregateUsageResponse, Error> {
+  operations::aggregate_usage(
+    &default_transport(),
+    endpoints,
+    access_token,
+    session_token,
+    usage_request,
+  )
+  .await
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// A struct containing the information and capabilities of a billing account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AccountInformation {
+  /// The name of the billing account.
+  pub account_name: String,
+  /// The account number of the billing account.
+  pub account_number: String,
+  /// The name of the organization that owns the account.
+  pub organization_name: String,
+  /// `true` if devices in this account can be activated, suspended and deactivated.
+  pub is_provisioning_allowed: bool,
+  /// The carriers that devices in this account can be activated on.
+  pub carriers: Vec<String>,
+  /// The features enabled for this account.
+  pub features: Vec<String>,
+  /// Array of [`IpPool`]s that static IP addresses can be assigned from.
+  pub ip_pools: Vec<IpPool>,
+  /// The device actions that are allowed for this account, e.g. `activate`.
+  pub operations_allowed: Vec<String>,
+}
+
+impl Default for AccountInformation {
+  fn default() -> AccountInformation {
+    AccountInformation {
+      account_name: String::with_capacity(32),
+      account_number: String::with_capacity(32),
+      organization_name: String::default(),
+      is_provisioning_allowed: bool::default(),
+      carriers: Vec::default(),
+      features: Vec::default(),
+      ip_pools: Vec::default(),
+      operations_allowed: Vec::default(),
+    }
+  }
+}
+
+/// A pool of IP addresses that static IPs can be assigned from.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IpPool {
+  /// The name of the pool, used as `carrier_ip_pool_name` in device actions.
+  pub pool_name: String,
+  /// The type of the pool, e.g. `Static`.
+  pub pool_type: String,
+  /// `true` if this pool is used when none is given.
+  pub is_default_pool: bool,
+}
@@ -0,0 +1,11 @@
+mod information;
+pub use information::AccountInformation;
+pub use information::IpPool;
+
+mod states;
+pub use states::AccountState;
+pub use states::AccountStatesResponse;
+pub use states::ServiceStates;
+
+mod plan;
+pub use plan::ServicePlan;
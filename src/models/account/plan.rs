@@ -0,0 +1,38 @@
+use crate::models::ExtendedAttribute;
+use serde::{Deserialize, Serialize};
+
+/// A service plan that is available to a billing account.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{AccountDeviceListRequest, ServicePlan};
+///
+/// fn has_valid_plan(adl: &AccountDeviceListRequest, plans: &[ServicePlan]) -> bool {
+///   adl
+///     .service_plan
+///     .as_deref()
+///     .is_none_or(|code| ServicePlan::find(plans, code).is_some())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServicePlan {
+  /// The description of the plan.
+  pub name: String,
+  /// The plan code, as used in `service_plan` fields.
+  pub code: String,
+  /// The data allowance of the plan in kilobytes.
+  pub size_kb: u64,
+  /// The carrier's own code for the plan.
+  pub carrier_service_plan_code: String,
+  /// Array of [`ExtendedAttribute`]s
+  pub extended_attributes: Vec<ExtendedAttribute>,
+}
+
+impl ServicePlan {
+  /// Returns the plan in `plans` with the plan code `code`, if any.
+  #[must_use]
+  pub fn find<'a>(plans: &'a [ServicePlan], code: &str) -> Option<&'a ServicePlan> {
+    plans.iter().find(|plan| plan.code == code)
+  }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// A struct containing the device states that are valid for a billing account, grouped by the
+/// service they belong to.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{AccountDeviceListRequest, AccountStatesResponse};
+///
+/// fn has_valid_state(adl: &AccountDeviceListRequest, states: &AccountStatesResponse) -> bool {
+///   adl
+///     .current_state
+///     .as_deref()
+///     .is_none_or(|state| states.contains_state(state))
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AccountStatesResponse {
+  /// The name of the billing account.
+  pub account_name: String,
+  /// Array of [`ServiceStates`], one per service.
+  pub state_service: Vec<ServiceStates>,
+}
+
+impl Default for AccountStatesResponse {
+  fn default() -> AccountStatesResponse {
+    AccountStatesResponse {
+      account_name: String::with_capacity(32),
+      state_service: Vec::default(),
+    }
+  }
+}
+
+impl AccountStatesResponse {
+  /// Returns `true` if any service of the account has a state named `state`.
+  #[must_use]
+  pub fn contains_state(&self, state: &str) -> bool {
+    self
+      .state_service
+      .iter()
+      .any(|service| service.contains_state(state))
+  }
+
+  /// Returns `true` if `service_name` has a state named `state`, i.e. if the pair can be used in a
+  /// [`GoToStateRequest`](crate::models::GoToStateRequest).
+  #[must_use]
+  pub fn is_valid_combination(&self, service_name: &str, state: &str) -> bool {
+    self
+      .state_service
+      .iter()
+      .any(|service| service.name == service_name && service.contains_state(state))
+  }
+}
+
+/// A service and the device states that belong to it.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServiceStates {
+  /// The name of the service.
+  pub name: String,
+  /// Array of [`AccountState`]s of this service.
+  pub state: Vec<AccountState>,
+}
+
+impl ServiceStates {
+  /// Returns `true` if this service has a state named `state`.
+  #[must_use]
+  pub fn contains_state(&self, state: &str) -> bool {
+    self.state.iter().any(|s| s.name == state)
+  }
+}
+
+/// A device state that is valid for a billing account.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AccountState {
+  /// The name of the state, as used in the `current_state` filter of
+  /// [`AccountDeviceListRequest`](crate::models::AccountDeviceListRequest).
+  pub name: String,
+  /// `true` if devices in this state are billed.
+  pub is_billable: bool,
+}
@@ -0,0 +1,97 @@
+use crate::models::{DeviceID, DeviceIdList};
+use serde::{Deserialize, Serialize};
+
+/// A device group of a billing account.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceGroup {
+  /// The name of the group.
+  pub name: String,
+  /// The description of the group.
+  pub description: String,
+  /// `true` for the default group that every device belongs to until it is moved.
+  pub is_default_group: bool,
+}
+
+/// A device group and the devices in it.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceGroupInformation {
+  /// The name of the group.
+  pub name: String,
+  /// The description of the group.
+  pub description: String,
+  /// The devices in the group, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  /// `true` if the group has more devices than were returned, see [`DeviceGroupPageRequest`].
+  pub has_more_data: bool,
+}
+
+/// The page of devices to request with a device group, see
+/// [`ThingSpaceClient::device_group_stream`](crate::api::ThingSpaceClient::device_group_stream).
+/// Sent as query parameters.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceGroupPageRequest {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Max number of devices returned for request.
+  pub max_number_of_devices: Option<i32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Used to request the next page of devices.
+  pub largest_device_id_seen: Option<i32>,
+}
+
+/// A struct containing a request to create a device group.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CreateDeviceGroupRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// The name of the new group.
+  pub group_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The description of the new group.
+  pub group_description: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Array of [`DeviceID`]s to add to the new group.
+  pub devices_to_add: Vec<DeviceID>,
+}
+
+impl Default for CreateDeviceGroupRequest {
+  fn default() -> CreateDeviceGroupRequest {
+    CreateDeviceGroupRequest {
+      account_name: String::with_capacity(32),
+      group_name: String::with_capacity(32),
+      group_description: Option::default(),
+      devices_to_add: Vec::default(),
+    }
+  }
+}
+
+/// A struct containing changes to a device group. Fields that are not set are left unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UpdateDeviceGroupRequest {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The new name of the group.
+  pub new_group_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The new description of the group.
+  pub new_group_description: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Array of [`DeviceID`]s to add to the group.
+  pub devices_to_add: Vec<DeviceID>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Array of [`DeviceID`]s to remove from the group. Removed devices are moved back to the
+  /// default group.
+  pub devices_to_remove: Vec<DeviceID>,
+}
+
+/// The response to a request that changes a device group.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SuccessResponse {
+  /// `true` if the change was made.
+  pub success: bool,
+}
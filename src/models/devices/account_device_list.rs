@@ -26,6 +26,9 @@ pub struct AccountDeviceListRequest {
   /// Only include devices that were added before this date and time.
   pub latest: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices in this device group.
+  pub group_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   /// Only include devices that have this service plan.
   pub service_plan: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,7 +36,7 @@ pub struct AccountDeviceListRequest {
   /// Constraints: `>= 0`, `<= 100`
   pub max_number_of_devices: Option<i32>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  /// Constraints: `>= 0`, `<= 100`
+  /// Used to request the next page of devices, see [`ThingSpaceClient::devices_stream`](crate::api::ThingSpaceClient::devices_stream)
   pub largest_device_id_seen: Option<i32>,
 }
 
@@ -0,0 +1,70 @@
+use super::DeviceID;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+/// The [`DeviceID`]s of one device that a device action applies to.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceIdList {
+  /// All identifiers of the device, e.g. its IMEI and ICCID.
+  pub device_ids: Vec<DeviceID>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The static IP address to assign to the device, for activations only.
+  pub ip_address: Option<String>,
+}
+
+/// The response to a device action. ThingSpace processes the action asynchronously and sends the
+/// result, with the same `request_id`, to the callback listener of the action's service, e.g.
+/// [`CarrierService`](crate::models::ServiceName::CarrierService).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ActionResponse {
+  /// requestId for the async device action
+  pub request_id: String,
+}
+
+impl Default for ActionResponse {
+  fn default() -> ActionResponse {
+    ActionResponse {
+      request_id: String::with_capacity(36),
+    }
+  }
+}
+
+/// A custom field that is stored with a device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CustomField {
+  /// The name of the custom field, `CustomField1` through `CustomField5`.
+  pub key: String,
+  /// The value of the custom field.
+  pub value: String,
+}
+
+impl CustomField {
+  /// Creates a [`CustomField`] that sets `key` to `value`.
+  #[must_use]
+  pub fn new(key: CustomFieldKey, value: &str) -> CustomField {
+    CustomField {
+      key: key.to_string(),
+      value: value.to_string(),
+    }
+  }
+}
+
+/// The names of the five custom fields ThingSpace stores with each device.
+#[derive(
+  Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, Serialize, PartialEq, Eq,
+)]
+pub enum CustomFieldKey {
+  /// `CustomField1`
+  CustomField1,
+  /// `CustomField2`
+  CustomField2,
+  /// `CustomField3`
+  CustomField3,
+  /// `CustomField4`
+  CustomField4,
+  /// `CustomField5`
+  CustomField5,
+}
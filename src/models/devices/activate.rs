@@ -0,0 +1,103 @@
+use super::{CustomField, DeviceIdList};
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request to activate service for one or more devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ActivateDevicesRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// The devices to activate, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  /// The service plan code to assign to all activated devices.
+  pub service_plan: String,
+  /// The ZIP code the MDNs of the activated devices are assigned from.
+  pub mdn_zip_code: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The name of the device group to add the activated devices to.
+  pub group_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The name of the pool of IP addresses to assign static IPs from.
+  pub carrier_ip_pool_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The Stock Keeping Unit (SKU) of a 4G device type.
+  pub sku_number: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// A cost center code to assign to the activated devices.
+  pub cost_center_code: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Up to five [`CustomField`]s to store with the activated devices.
+  pub custom_fields: Vec<CustomField>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// [`PlaceOfUse`]: The customer name and address where the devices are primarily used.
+  pub primary_place_of_use: Option<PlaceOfUse>,
+}
+
+impl Default for ActivateDevicesRequest {
+  fn default() -> ActivateDevicesRequest {
+    ActivateDevicesRequest {
+      account_name: String::with_capacity(32),
+      devices: vec![DeviceIdList::default()],
+      service_plan: String::with_capacity(32),
+      mdn_zip_code: String::with_capacity(5),
+      group_name: Option::default(),
+      carrier_ip_pool_name: Option::default(),
+      sku_number: Option::default(),
+      cost_center_code: Option::default(),
+      custom_fields: Vec::default(),
+      primary_place_of_use: Option::default(),
+    }
+  }
+}
+
+/// The customer name and address where devices are primarily used, for tax purposes.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PlaceOfUse {
+  /// [`CustomerName`]: The name of the customer.
+  pub customer_name: CustomerName,
+  /// [`Address`]: The address where the devices are primarily used.
+  pub address: Address,
+}
+
+/// The name of a customer.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CustomerName {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Title, such as `Mr.` or `Dr.`
+  pub title: Option<String>,
+  /// First name
+  pub first_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Middle name
+  pub middle_name: Option<String>,
+  /// Last name
+  pub last_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Suffix, such as `Jr.`
+  pub suffix: Option<String>,
+}
+
+/// A postal address.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Address {
+  /// Street address
+  pub address_line1: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Apartment, suite or floor
+  pub address_line2: Option<String>,
+  /// City
+  pub city: String,
+  /// Two-letter state code
+  pub state: String,
+  /// Five-digit ZIP code
+  pub zip: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Four-digit ZIP+4 extension
+  pub zip4: Option<String>,
+  /// Two-letter country code
+  pub country: String,
+}
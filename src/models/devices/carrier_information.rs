@@ -1,3 +1,4 @@
+use super::DeviceState;
 use serde::{Deserialize, Serialize};
 
 /// A struct containing information about the device's carrier.
@@ -9,8 +10,8 @@ pub struct CarrierInformation {
   pub carrier_name: String,
   /// Service plan
   pub service_plan: String,
-  /// Service state
-  pub state: String,
+  /// [`DeviceState`]: Service state
+  pub state: DeviceState,
 }
 
 impl Default for CarrierInformation {
@@ -18,7 +19,7 @@ impl Default for CarrierInformation {
     CarrierInformation {
       carrier_name: String::with_capacity(32),
       service_plan: String::with_capacity(32),
-      state: String::with_capacity(16),
+      state: DeviceState::default(),
     }
   }
 }
@@ -0,0 +1,34 @@
+use super::{CustomField, DeviceActionFilter, DeviceIdList};
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request to change the custom field values of one or more devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UpdateCustomFieldsRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// The devices to change, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  #[serde(flatten)]
+  /// [`DeviceActionFilter`]: Selects devices without listing them.
+  pub filter: DeviceActionFilter,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices that have this service plan.
+  pub service_plan: Option<String>,
+  /// The [`CustomField`]s to set. Fields that are not listed keep their value.
+  pub custom_fields_to_update: Vec<CustomField>,
+}
+
+impl Default for UpdateCustomFieldsRequest {
+  fn default() -> UpdateCustomFieldsRequest {
+    UpdateCustomFieldsRequest {
+      account_name: String::with_capacity(32),
+      devices: Vec::new(),
+      filter: DeviceActionFilter::default(),
+      service_plan: Option::default(),
+      custom_fields_to_update: Vec::with_capacity(5),
+    }
+  }
+}
@@ -1,57 +1,209 @@
-use super::{CarrierInformation, DeviceID, ExtendedAttribute};
+use super::{
+  CarrierInformation, CustomFieldKey, DeviceAttributes, DeviceID, DeviceIdKind, DeviceState,
+  ExtendedAttribute,
+};
+use crate::models::timestamp::optional_timestamp;
+use iso8601::DateTime;
 use serde::{Deserialize, Serialize};
-// use iso8601::DateTime;
+use serde_json::{Map, Value};
 
 /// A struct containing a Device.
+///
+/// Every list may hold any number of entries, e.g. no MDN before activation or several group
+/// names. Fields this version of the SDK does not know are kept in [`Device::other`].
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{Device, DeviceState};
+///
+/// let body = r#"{
+///   "accountName": "0000123456-00001",
+///   "deviceIds": [
+///     { "id": "353879234252633", "kind": "imei" },
+///     { "id": "89148000000000000001", "kind": "iccId" }
+///   ],
+///   "carrierInformations": [
+///     { "carrierName": "Verizon Wireless", "servicePlan": "M2MPlan", "state": "active" }
+///   ],
+///   "createdAt": "2018-04-27T19:09:10.000Z",
+///   "lastConnectionDate": "",
+///   "groupNames": ["Default: 0000123456-00001", "Sensors"],
+///   "ipAddress": "10.0.0.1"
+/// }"#;
+///
+/// let device: Device = serde_json::from_str(body).unwrap();
+/// assert_eq!(device.imei(), Some("353879234252633"));
+/// assert_eq!(device.iccid(), Some("89148000000000000001"));
+/// assert_eq!(device.mdn(), None);
+/// assert_eq!(device.state(), Some(&DeviceState::Active));
+/// assert_eq!(device.created_at.unwrap().time.hour, 19);
+/// assert!(device.last_connection_date.is_none());
+/// assert_eq!(device.group_names.len(), 2);
+/// assert_eq!(device.other["ipAddress"], "10.0.0.1");
+/// ```
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct Device {
   /// Account name
   pub account_name: String,
-  /// Billing cycle end date
-  pub billing_cycle_end_date: String,
-  /// Array of [`CarrierInformation`] objects (should only conatain 1 object)
-  pub carrier_informations: [CarrierInformation; 1],
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// Billing cycle end date, `None` if ThingSpace did not send one
+  pub billing_cycle_end_date: Option<DateTime>,
+  /// Array of [`CarrierInformation`] objects, usually one
+  pub carrier_informations: Vec<CarrierInformation>,
   /// Connection state
   pub connected: bool,
-  /// Device creation date
-  pub created_at: String,
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// Device creation date, `None` if ThingSpace did not send one
+  pub created_at: Option<DateTime>,
   /// Array of [`DeviceID`]s
-  pub device_ids: [DeviceID; 6],
-  /// Array of [`ExtendedAttribute`]s
+  pub device_ids: Vec<DeviceID>,
+  /// Array of [`ExtendedAttribute`]s, see [`Device::attributes`] for a typed view
   pub extended_attributes: Vec<ExtendedAttribute>,
-  /// Array of device group names (should only contain 1 String, the default group name)
-  pub group_names: [String; 1],
+  /// Array of device group names, starting with the default group name
+  pub group_names: Vec<String>,
   /// Last activated by user
   pub last_activation_by: String,
-  /// Last activation date
-  pub last_activation_date: String,
-  /// Last connection date
-  pub last_connection_date: String,
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// Last activation date, `None` if ThingSpace did not send one
+  pub last_activation_date: Option<DateTime>,
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// Last connection date, `None` if ThingSpace did not send one
+  pub last_connection_date: Option<DateTime>,
+  #[serde(flatten)]
+  /// Fields that are not part of this struct, keyed by their JSON name
+  pub other: Map<String, Value>,
 }
 
 impl Default for Device {
   fn default() -> Device {
     Device {
       account_name: String::with_capacity(32),
-      billing_cycle_end_date: String::with_capacity(32),
-      carrier_informations: [CarrierInformation::default()],
+      billing_cycle_end_date: None,
+      carrier_informations: Vec::with_capacity(1),
       connected: bool::default(),
-      created_at: String::with_capacity(32),
-      device_ids: [
-        DeviceID::default(),
-        DeviceID::default(),
-        DeviceID::default(),
-        DeviceID::default(),
-        DeviceID::default(),
-        DeviceID::default(),
-      ],
-      extended_attributes: vec![ExtendedAttribute::default(); 26],
-      group_names: [String::with_capacity(32)],
+      created_at: None,
+      device_ids: Vec::with_capacity(6),
+      extended_attributes: Vec::default(),
+      group_names: Vec::with_capacity(1),
       last_activation_by: String::with_capacity(32),
-      last_activation_date: String::with_capacity(32),
-      last_connection_date: String::with_capacity(32),
+      last_activation_date: None,
+      last_connection_date: None,
+      other: Map::default(),
     }
   }
 }
+
+impl Device {
+  /// Returns the [`DeviceState`] of the first carrier, if there is one.
+  #[must_use]
+  pub fn state(&self) -> Option<&DeviceState> {
+    self
+      .carrier_informations
+      .first()
+      .map(|carrier| &carrier.state)
+  }
+
+  /// Returns the first identifier of the given `kind`, if the device has one.
+  #[must_use]
+  pub fn id_of(&self, kind: &DeviceIdKind) -> Option<&str> {
+    self
+      .device_ids
+      .iter()
+      .find(|device_id| device_id.kind == *kind)
+      .map(|device_id| device_id.id.as_str())
+  }
+
+  /// Returns the device's IMEI, if it has one.
+  #[must_use]
+  pub fn imei(&self) -> Option<&str> {
+    self.id_of(&DeviceIdKind::Imei)
+  }
+
+  /// Returns the device's ICCID, if it has one.
+  #[must_use]
+  pub fn iccid(&self) -> Option<&str> {
+    self.id_of(&DeviceIdKind::Iccid)
+  }
+
+  /// Returns the device's MDN, if it has one.
+  #[must_use]
+  pub fn mdn(&self) -> Option<&str> {
+    self.id_of(&DeviceIdKind::Mdn)
+  }
+
+  /// Returns a typed view over [`Device::extended_attributes`].
+  #[must_use]
+  pub fn attributes(&self) -> DeviceAttributes {
+    DeviceAttributes::from(self.extended_attributes.as_slice())
+  }
+
+  /// Sets the custom field `key` in [`Device::extended_attributes`]. This only changes the local
+  /// copy, use [`ThingSpaceClient::set_custom_field`] to store the value in ThingSpace.
+  ///
+  /// [`ThingSpaceClient::set_custom_field`]: crate::api::ThingSpaceClient::set_custom_field
+  pub fn set_custom_field(&mut self, key: CustomFieldKey, value: &str) {
+    let key = key.to_string();
+    match self.extended_attributes.iter_mut().find(|a| a.key == key) {
+      Some(attribute) => attribute.value = Some(value.to_string()),
+      None => self.extended_attributes.push(ExtendedAttribute {
+        key,
+        value: Some(value.to_string()),
+      }),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Device;
+  use serde_json::json;
+
+  #[test]
+  fn missing_and_empty_lists_are_empty() {
+    let missing: Device =
+      serde_json::from_value(json!({ "accountName": "0000123456-00001" })).unwrap();
+    let empty: Device = serde_json::from_value(json!({
+      "accountName": "0000123456-00001",
+      "carrierInformations": [],
+      "extendedAttributes": [],
+    }))
+    .unwrap();
+
+    for device in [missing, empty] {
+      assert!(device.carrier_informations.is_empty());
+      assert!(device.extended_attributes.is_empty());
+      assert_eq!(device.state(), None);
+      assert!(device.attributes().ip_address.is_none());
+      assert!(device.other.is_empty());
+    }
+  }
+
+  #[test]
+  fn unknown_fields_are_kept_in_other() {
+    let body = json!({
+      "accountName": "0000123456-00001",
+      "connected": true,
+      "ipAddress": "10.0.0.1",
+      "lastConnectionDate": "",
+      "simOtaDate": null,
+      "location": { "lat": 40.7, "long": -74.0 },
+    });
+
+    let device: Device = serde_json::from_value(body).unwrap();
+    assert!(device.connected);
+    assert_eq!(device.other.len(), 3);
+    assert_eq!(device.other["ipAddress"], "10.0.0.1");
+    assert_eq!(device.other["simOtaDate"], json!(null));
+    assert_eq!(device.other["location"]["lat"], 40.7);
+    assert!(!device.other.contains_key("accountName"));
+    assert!(!device.other.contains_key("lastConnectionDate"));
+
+    // The unknown fields are written back next to the known ones.
+    let value = serde_json::to_value(&device).unwrap();
+    assert_eq!(value["ipAddress"], "10.0.0.1");
+    assert_eq!(value["location"]["long"], -74.0);
+    assert_eq!(value["accountName"], "0000123456-00001");
+  }
+}
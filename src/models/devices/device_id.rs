@@ -1,24 +1,233 @@
-use serde::{Deserialize, Serialize};
+use crate::models::{Error, InvalidDeviceId};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+/// The type of a device identifier.
+///
+/// Parsing ignores case, so both `iccId` and `iccid` are read as [`DeviceIdKind::Iccid`]. Kinds
+/// are serialized with ThingSpace's casing, e.g. `iccId`. Kinds this version of the SDK does not
+/// know are kept in [`DeviceIdKind::Unknown`] and sent back as they were received.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::DeviceIdKind;
+///
+/// let kind: DeviceIdKind = serde_json::from_str("\"iccId\"").unwrap();
+/// assert_eq!(kind, DeviceIdKind::Iccid);
+/// assert_eq!(kind.to_string(), "iccId");
+///
+/// let kind: DeviceIdKind = serde_json::from_str("\"imsi\"").unwrap();
+/// assert_eq!(kind, DeviceIdKind::Unknown("imsi".to_string()));
+/// ```
+#[derive(Clone, Debug, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum DeviceIdKind {
+  /// Electronic Serial Number, 11 decimal digits.
+  Esn,
+  /// eUICC Identifier, 32 digits.
+  Eid,
+  /// Integrated Circuit Card ID, 18 to 20 digits ending in a Luhn check digit.
+  #[strum(to_string = "iccId")]
+  Iccid,
+  /// International Mobile Equipment Identity, 15 digits ending in a Luhn check digit, or a
+  /// 16 digit IMEISV.
+  Imei,
+  /// Mobile Directory Number, 10 digits.
+  Mdn,
+  /// Mobile Equipment Identifier, 14 hexadecimal digits.
+  Meid,
+  /// Mobile Station ISDN Number, 10 to 15 digits.
+  Msisdn,
+  /// A kind this version of the SDK does not know.
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl Default for DeviceIdKind {
+  fn default() -> DeviceIdKind {
+    DeviceIdKind::Unknown(String::with_capacity(8))
+  }
+}
+
+impl Serialize for DeviceIdKind {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for DeviceIdKind {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DeviceIdKind, D::Error> {
+    let kind = String::deserialize(deserializer)?;
+    // Never fails, unknown kinds end up in `DeviceIdKind::Unknown`.
+    Ok(DeviceIdKind::from_str(&kind).unwrap_or(DeviceIdKind::Unknown(kind)))
+  }
+}
+
+impl DeviceIdKind {
+  /// Checks the length and characters of `id` for this kind, and the check digit of IMEIs and
+  /// ICCIDs. [`DeviceIdKind::Unknown`] accepts any `id`.
+  ///
+  /// # Errors
+  /// Returns the [`InvalidDeviceId`] reason if `id` is not a valid identifier of this kind.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::models::DeviceIdKind;
+  ///
+  /// assert!(DeviceIdKind::Imei.validate("353879234252633").is_ok());
+  /// assert!(DeviceIdKind::Imei.validate("353879234252634").is_err());
+  /// assert!(DeviceIdKind::Meid.validate("A10000009296F2").is_ok());
+  /// ```
+  pub fn validate(&self, id: &str) -> Result<(), InvalidDeviceId> {
+    let digits = id.bytes().all(|b| b.is_ascii_digit());
+    let reason = match self {
+      DeviceIdKind::Esn if id.len() != 11 || !digits => "must be 11 decimal digits",
+      DeviceIdKind::Eid if id.len() != 32 || !digits => "must be 32 digits",
+      DeviceIdKind::Iccid if !(18..=20).contains(&id.len()) || !digits => "must be 18 to 20 digits",
+      DeviceIdKind::Iccid if !luhn(id) => "has an invalid check digit",
+      DeviceIdKind::Imei if !(15..=16).contains(&id.len()) || !digits => {
+        "must be 15 digits, or 16 for an IMEISV"
+      }
+      DeviceIdKind::Imei if id.len() == 15 && !luhn(id) => "has an invalid check digit",
+      DeviceIdKind::Mdn if id.len() != 10 || !digits => "must be 10 digits",
+      DeviceIdKind::Meid if id.len() != 14 || !id.bytes().all(|b| b.is_ascii_hexdigit()) => {
+        "must be 14 hexadecimal digits"
+      }
+      DeviceIdKind::Msisdn if !(10..=15).contains(&id.len()) || !digits => {
+        "must be 10 to 15 digits"
+      }
+      _ => return Ok(()),
+    };
+
+    Err(InvalidDeviceId {
+      kind: self.clone(),
+      id: id.to_string(),
+      reason,
+    })
+  }
+}
+
+/// Returns `true` if the last digit of `digits` is its Luhn check digit.
+// `u32::is_multiple_of` needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn luhn(digits: &str) -> bool {
+  let sum: u32 = digits
+    .bytes()
+    .rev()
+    .map(|b| u32::from(b - b'0'))
+    .enumerate()
+    .map(|(i, d)| match (i % 2, d * 2) {
+      (0, _) => d,
+      (_, doubled) if doubled > 9 => doubled - 9,
+      (_, doubled) => doubled,
+    })
+    .sum();
+
+  sum % 10 == 0
+}
 
 /// A struct containing a Device ID type and ID.
+///
+/// The constructors check the ID before it is sent, so a malformed ID does not cost a failed
+/// request.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{DeviceID, DeviceIdKind};
+///
+/// let device_id = DeviceID::imei("353879234252633").unwrap();
+/// assert_eq!(device_id.kind, DeviceIdKind::Imei);
+///
+/// assert!(DeviceID::iccid("89148000000000000002").is_err());
+/// ```
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeviceID {
   /// Device ID
   pub id: String,
-  /// The type of the device identifier. Valid types of identifiers are:
-  /// ESN (decimal), EID, ICCID (up to 20 digits), IMEI (up to 16 digits), MDN, MEID (hexadecimal), MSISDN.
-  pub kind: String,
+  /// [`DeviceIdKind`]: The type of the device identifier.
+  pub kind: DeviceIdKind,
 }
 
 impl Default for DeviceID {
   fn default() -> DeviceID {
     DeviceID {
       id: String::with_capacity(32),
-      kind: String::with_capacity(8),
+      kind: DeviceIdKind::default(),
     }
   }
 }
 
+impl DeviceID {
+  /// Creates a [`DeviceID`] after checking `id` with [`DeviceIdKind::validate`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not a valid identifier of `kind`.
+  pub fn new(kind: DeviceIdKind, id: &str) -> Result<DeviceID, Error> {
+    kind.validate(id)?;
+    Ok(DeviceID {
+      id: id.to_string(),
+      kind,
+    })
+  }
+
+  /// Creates an ESN [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 11 decimal digits.
+  pub fn esn(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Esn, id)
+  }
+
+  /// Creates an EID [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 32 digits.
+  pub fn eid(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Eid, id)
+  }
+
+  /// Creates an ICCID [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 18 to 20 digits or fails the Luhn check.
+  pub fn iccid(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Iccid, id)
+  }
+
+  /// Creates an IMEI [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 15 digits that pass the Luhn check, or a
+  /// 16 digit IMEISV.
+  pub fn imei(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Imei, id)
+  }
+
+  /// Creates an MDN [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 10 digits.
+  pub fn mdn(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Mdn, id)
+  }
+
+  /// Creates a MEID [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 14 hexadecimal digits.
+  pub fn meid(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Meid, id)
+  }
+
+  /// Creates an MSISDN [`DeviceID`].
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if `id` is not 10 to 15 digits.
+  pub fn msisdn(id: &str) -> Result<DeviceID, Error> {
+    DeviceID::new(DeviceIdKind::Msisdn, id)
+  }
+
+  /// Checks [`DeviceID::id`] with [`DeviceIdKind::validate`], e.g. for IDs built by hand.
+  /// # Errors
+  /// Returns `Error::InvalidDeviceId` if the ID is not a valid identifier of its kind.
+  pub fn validate(&self) -> Result<(), Error> {
+    Ok(self.kind.validate(&self.id)?)
+  }
+}
+
 /// A struct containing a Device ID type and ID.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeviceIdSearch {
@@ -30,9 +239,8 @@ pub struct DeviceIdSearch {
   #[serde(skip_serializing_if = "Option::is_none")]
   /// The identifier must end with the specified string.
   pub ends_with: Option<String>,
-  /// The type of the device identifier. Valid types of identifiers are:
-  /// ESN (decimal), EID, ICCID (up to 20 digits), IMEI (up to 16 digits), MDN, MEID (hexadecimal), MSISDN.
-  pub kind: String,
+  /// [`DeviceIdKind`]: The type of the device identifier.
+  pub kind: DeviceIdKind,
 }
 
 impl Default for DeviceIdSearch {
@@ -41,7 +249,125 @@ impl Default for DeviceIdSearch {
       contains: String::with_capacity(32),
       starts_with: Option::default(),
       ends_with: Option::default(),
-      kind: String::with_capacity(8),
+      kind: DeviceIdKind::default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DeviceID, DeviceIdKind, luhn};
+  use crate::models::Error;
+
+  #[test]
+  fn luhn_check_digit() {
+    assert!(luhn("79927398713"));
+    assert!(!luhn("79927398710"));
+    assert!(luhn("353879234252633"));
+    assert!(!luhn("353879234252634"));
+    // Zeros and the empty string add up to a multiple of 10.
+    assert!(luhn("0"));
+    assert!(luhn(""));
+  }
+
+  #[test]
+  fn iccid_lengths_and_check_digit() {
+    assert!(DeviceIdKind::Iccid.validate("891480000000000001").is_ok());
+    assert!(DeviceIdKind::Iccid.validate("8991101200003204514").is_ok());
+    assert!(DeviceIdKind::Iccid.validate("89148000000000000001").is_ok());
+
+    let error = DeviceIdKind::Iccid
+      .validate("89148000000000000002")
+      .unwrap_err();
+    assert_eq!(error.reason, "has an invalid check digit");
+
+    for id in [
+      "89148000000000001",
+      "891480000000000000013",
+      "8914800000000000000a",
+    ] {
+      let error = DeviceIdKind::Iccid.validate(id).unwrap_err();
+      assert_eq!(error.reason, "must be 18 to 20 digits", "{id}");
     }
   }
+
+  #[test]
+  fn imeisv_skips_the_check_digit() {
+    assert!(DeviceIdKind::Imei.validate("353879234252633").is_ok());
+    assert!(DeviceIdKind::Imei.validate("3538792342526301").is_ok());
+    assert!(DeviceIdKind::Imei.validate("35387923425263").is_err());
+    assert!(DeviceIdKind::Imei.validate("35387923425263301").is_err());
+  }
+
+  #[test]
+  fn lengths_and_charsets() {
+    assert!(DeviceIdKind::Esn.validate("12345678901").is_ok());
+    assert!(DeviceIdKind::Esn.validate("1234567890").is_err());
+    assert!(DeviceIdKind::Eid.validate(&"1".repeat(32)).is_ok());
+    assert!(DeviceIdKind::Eid.validate(&"1".repeat(31)).is_err());
+    assert!(DeviceIdKind::Mdn.validate("5551234567").is_ok());
+    assert!(DeviceIdKind::Mdn.validate("555-123-45").is_err());
+    assert!(DeviceIdKind::Meid.validate("A10000009296F2").is_ok());
+    assert!(DeviceIdKind::Meid.validate("a10000009296f2").is_ok());
+    assert!(DeviceIdKind::Meid.validate("G10000009296F2").is_err());
+    assert!(DeviceIdKind::Msisdn.validate("5551234567").is_ok());
+    assert!(DeviceIdKind::Msisdn.validate("155512345678901").is_ok());
+    assert!(DeviceIdKind::Msisdn.validate("1555123456789012").is_err());
+  }
+
+  #[test]
+  fn non_ascii_digits_are_rejected() {
+    // Full-width digits are not ASCII digits, and take three bytes each.
+    assert!(DeviceIdKind::Mdn.validate("５５５１２３４５６７").is_err());
+    assert!(DeviceIdKind::Imei.validate("35387923425263３").is_err());
+  }
+
+  #[test]
+  fn unknown_kinds_accept_any_id() {
+    let kind = DeviceIdKind::Unknown("imsi".to_string());
+    assert!(kind.validate("").is_ok());
+    assert!(kind.validate("anything at all").is_ok());
+  }
+
+  #[test]
+  fn constructors_return_invalid_device_id() {
+    let device_id = DeviceID::mdn("5551234567").unwrap();
+    assert_eq!(device_id.kind, DeviceIdKind::Mdn);
+    assert!(device_id.validate().is_ok());
+
+    let Err(Error::InvalidDeviceId(error)) = DeviceID::imei("353879234252634") else {
+      panic!("expected Error::InvalidDeviceId");
+    };
+    assert_eq!(error.kind, DeviceIdKind::Imei);
+    assert_eq!(error.id, "353879234252634");
+
+    let by_hand = DeviceID {
+      id: "123".to_string(),
+      kind: DeviceIdKind::Esn,
+    };
+    assert!(by_hand.validate().is_err());
+  }
+
+  #[test]
+  fn kinds_round_trip_with_thingspace_casing() {
+    for (json, kind) in [
+      ("\"iccId\"", DeviceIdKind::Iccid),
+      ("\"ICCID\"", DeviceIdKind::Iccid),
+      ("\"imei\"", DeviceIdKind::Imei),
+      ("\"msisdn\"", DeviceIdKind::Msisdn),
+    ] {
+      assert_eq!(serde_json::from_str::<DeviceIdKind>(json).unwrap(), kind);
+    }
+
+    assert_eq!(
+      serde_json::to_string(&DeviceIdKind::Iccid).unwrap(),
+      "\"iccId\""
+    );
+    assert_eq!(
+      serde_json::to_string(&DeviceIdKind::Meid).unwrap(),
+      "\"meid\""
+    );
+    let unknown: DeviceIdKind = serde_json::from_str("\"IMSI\"").unwrap();
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"IMSI\"");
+  }
 }
@@ -1,4 +1,7 @@
+use super::{CustomFieldKey, PlaceOfUse};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Any extended attributes for the device, as Key and Value pairs.
 /// The pairs listed below are returned as part of the response for a single device,
@@ -22,3 +25,137 @@ impl Default for ExtendedAttribute {
     }
   }
 }
+
+/// A typed view over a device's [`ExtendedAttribute`]s, see [`Device::attributes`].
+///
+/// Empty values are `None`. Keys without a field of their own are kept in
+/// [`DeviceAttributes::other`].
+///
+/// [`Device::attributes`]: crate::models::Device::attributes
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{CustomFieldKey, DeviceAttributes, ExtendedAttribute};
+///
+/// let attribute = |key: &str, value: &str| ExtendedAttribute {
+///   key: key.to_string(),
+///   value: Some(value.to_string()),
+/// };
+/// let attributes = DeviceAttributes::from(
+///   [
+///     attribute("IPAddress", "10.0.0.1"),
+///     attribute("CustomField2", "Warehouse 7"),
+///     attribute("PrimaryPlaceOfUseCity", "Seattle"),
+///     attribute("PrimaryPlaceOfUseEmail", "ops@example.com"),
+///   ]
+///   .as_slice(),
+/// );
+///
+/// assert_eq!(attributes.ip_address.as_deref(), Some("10.0.0.1"));
+/// assert_eq!(attributes.custom_field(CustomFieldKey::CustomField2), Some("Warehouse 7"));
+/// assert_eq!(attributes.primary_place_of_use.unwrap().address.city, "Seattle");
+/// assert_eq!(attributes.other["PrimaryPlaceOfUseEmail"], "ops@example.com");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeviceAttributes {
+  /// `IPAddress`: The device's static IP address
+  pub ip_address: Option<String>,
+  /// `SkuNumber`: The Stock Keeping Unit of the device
+  pub sku_number: Option<String>,
+  /// `PreIMEI`: The IMEI the device had before its last device change
+  pub pre_imei: Option<String>,
+  /// `PreSkuNumber`: The SKU the device had before its last device change
+  pub pre_sku_number: Option<String>,
+  /// `CustomField1` through `CustomField5`, see [`DeviceAttributes::custom_field`]
+  pub custom_fields: [Option<String>; 5],
+  /// The `PrimaryPlaceOfUse*` keys, if any of them has a value
+  pub primary_place_of_use: Option<PlaceOfUse>,
+  /// Every other key with a value
+  pub other: HashMap<String, String>,
+}
+
+impl DeviceAttributes {
+  /// Returns the value of the custom field `key`.
+  #[must_use]
+  pub fn custom_field(&self, key: CustomFieldKey) -> Option<&str> {
+    self.custom_fields[key as usize].as_deref()
+  }
+}
+
+/// The `PrimaryPlaceOfUse*` keys that are read into a [`PlaceOfUse`].
+const PLACE_OF_USE_KEYS: [&str; 12] = [
+  "Title",
+  "FirstName",
+  "MiddleName",
+  "LastName",
+  "Suffix",
+  "AddressLine1",
+  "AddressLine2",
+  "City",
+  "State",
+  "ZipCode",
+  "ZipCode4",
+  "Country",
+];
+
+impl From<&[ExtendedAttribute]> for DeviceAttributes {
+  fn from(extended_attributes: &[ExtendedAttribute]) -> DeviceAttributes {
+    let mut attributes = DeviceAttributes::default();
+    let mut place = PlaceOfUse::default();
+    let mut has_place = false;
+
+    for attribute in extended_attributes {
+      let Some(value) = attribute.value.as_deref().filter(|v| !v.is_empty()) else {
+        continue;
+      };
+      let value = value.to_string();
+
+      if let Ok(key) = CustomFieldKey::from_str(&attribute.key) {
+        attributes.custom_fields[key as usize] = Some(value);
+        continue;
+      }
+
+      let place_key = attribute
+        .key
+        .strip_prefix("PrimaryPlaceOfUse")
+        .filter(|k| PLACE_OF_USE_KEYS.contains(k));
+      if let Some(place_key) = place_key {
+        let name = &mut place.customer_name;
+        let address = &mut place.address;
+        has_place = true;
+        match place_key {
+          "Title" => name.title = Some(value),
+          "FirstName" => name.first_name = value,
+          "MiddleName" => name.middle_name = Some(value),
+          "LastName" => name.last_name = value,
+          "Suffix" => name.suffix = Some(value),
+          "AddressLine1" => address.address_line1 = value,
+          "AddressLine2" => address.address_line2 = Some(value),
+          "City" => address.city = value,
+          "State" => address.state = value,
+          "ZipCode" => address.zip = value,
+          "ZipCode4" => address.zip4 = Some(value),
+          "Country" => address.country = value,
+          _ => {}
+        }
+        continue;
+      }
+
+      match attribute.key.as_str() {
+        "IPAddress" => attributes.ip_address = Some(value),
+        "SkuNumber" => attributes.sku_number = Some(value),
+        "PreIMEI" => attributes.pre_imei = Some(value),
+        "PreSkuNumber" => attributes.pre_sku_number = Some(value),
+        _ => {
+          attributes.other.insert(attribute.key.clone(), value);
+        }
+      }
+    }
+
+    if has_place {
+      attributes.primary_place_of_use = Some(place);
+    }
+
+    attributes
+  }
+}
@@ -0,0 +1,107 @@
+use super::{CustomField, DeviceIdList};
+use serde::{Deserialize, Serialize};
+
+/// Selects devices by group or custom field values, in addition to or instead of listing them in
+/// `devices`.
+///
+/// The service plan filter is a field of each request, because its name differs between
+/// endpoints, e.g. `currentServicePlan` in a [`ChangeServicePlanRequest`].
+///
+/// [`ChangeServicePlanRequest`]: crate::models::ChangeServicePlanRequest
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceActionFilter {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices in this device group.
+  pub group_name: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Only include devices that have all of these [`CustomField`] values.
+  pub custom_fields: Vec<CustomField>,
+}
+
+/// A struct containing a request to suspend or restore service for one or more devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceStateRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// The devices to change, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  #[serde(flatten)]
+  /// [`DeviceActionFilter`]: Selects devices without listing them.
+  pub filter: DeviceActionFilter,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices that have this service plan.
+  pub service_plan: Option<String>,
+}
+
+impl Default for DeviceStateRequest {
+  fn default() -> DeviceStateRequest {
+    DeviceStateRequest {
+      account_name: String::with_capacity(32),
+      devices: Vec::new(),
+      filter: DeviceActionFilter::default(),
+      service_plan: Option::default(),
+    }
+  }
+}
+
+/// A struct containing a request to deactivate service for one or more devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeactivateDevicesRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// The devices to deactivate, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  #[serde(flatten)]
+  /// [`DeviceActionFilter`]: Selects devices without listing them.
+  pub filter: DeviceActionFilter,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices that have this service plan.
+  pub service_plan: Option<String>,
+  /// The reason for the deactivation, e.g. `FF`.
+  pub reason_code: String,
+  /// Waive the early termination fee, if one applies.
+  pub etf_waiver: bool,
+  /// Delete the devices from the account once they are deactivated.
+  pub delete_after_deactivation: bool,
+}
+
+impl Default for DeactivateDevicesRequest {
+  fn default() -> DeactivateDevicesRequest {
+    DeactivateDevicesRequest {
+      account_name: String::with_capacity(32),
+      devices: Vec::new(),
+      filter: DeviceActionFilter::default(),
+      service_plan: Option::default(),
+      reason_code: String::with_capacity(2),
+      etf_waiver: bool::default(),
+      delete_after_deactivation: bool::default(),
+    }
+  }
+}
+
+/// A struct containing a request to delete deactivated devices from an account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeleteDevicesRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// The devices to delete, see [`DeviceIdList`]. Only deactivated devices can be deleted.
+  pub devices_to_delete: Vec<DeviceIdList>,
+}
+
+impl Default for DeleteDevicesRequest {
+  fn default() -> DeleteDevicesRequest {
+    DeleteDevicesRequest {
+      account_name: String::with_capacity(32),
+      devices_to_delete: Vec::new(),
+    }
+  }
+}
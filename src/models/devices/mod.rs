@@ -7,10 +7,40 @@ pub use device::Device;
 
 mod device_id;
 pub use device_id::DeviceID;
+pub use device_id::DeviceIdKind;
 pub use device_id::DeviceIdSearch;
 
 mod carrier_information;
 pub use carrier_information::CarrierInformation;
 
 mod extended_attribute;
+pub use extended_attribute::DeviceAttributes;
 pub use extended_attribute::ExtendedAttribute;
+
+mod action;
+pub use action::ActionResponse;
+pub use action::CustomField;
+pub use action::CustomFieldKey;
+pub use action::DeviceIdList;
+
+mod activate;
+pub use activate::ActivateDevicesRequest;
+pub use activate::Address;
+pub use activate::CustomerName;
+pub use activate::PlaceOfUse;
+
+mod custom_fields;
+pub use custom_fields::UpdateCustomFieldsRequest;
+
+mod lifecycle;
+pub use lifecycle::DeactivateDevicesRequest;
+pub use lifecycle::DeleteDevicesRequest;
+pub use lifecycle::DeviceActionFilter;
+pub use lifecycle::DeviceStateRequest;
+
+mod plan;
+pub use plan::ChangeServicePlanRequest;
+pub use plan::GoToStateRequest;
+
+mod state;
+pub use state::DeviceState;
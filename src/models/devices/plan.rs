@@ -0,0 +1,96 @@
+use super::{DeviceActionFilter, DeviceIdList, PlaceOfUse};
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request to change the service plan of one or more devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChangeServicePlanRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// The devices to change, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  #[serde(flatten)]
+  /// [`DeviceActionFilter`]: Selects devices without listing them.
+  pub filter: DeviceActionFilter,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Only include devices that currently have this service plan.
+  pub current_service_plan: Option<String>,
+  /// The code of the new service plan.
+  pub service_plan: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The name of the pool of IP addresses to assign static IPs from.
+  pub carrier_ip_pool_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// When the new service plan takes effect. Defaults to immediately.
+  pub take_effect: Option<DateTime>,
+}
+
+impl Default for ChangeServicePlanRequest {
+  fn default() -> ChangeServicePlanRequest {
+    ChangeServicePlanRequest {
+      account_name: String::with_capacity(32),
+      devices: Vec::new(),
+      filter: DeviceActionFilter::default(),
+      current_service_plan: Option::default(),
+      service_plan: String::with_capacity(32),
+      carrier_ip_pool_name: Option::default(),
+      take_effect: Option::default(),
+    }
+  }
+}
+
+/// A struct containing a request to move one or more devices to a custom device state.
+/// The result is sent to the [`StateService`](crate::models::ServiceName::StateService) callback
+/// listener.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GoToStateRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// The devices to change, see [`DeviceIdList`].
+  pub devices: Vec<DeviceIdList>,
+  #[serde(flatten)]
+  /// [`DeviceActionFilter`]: Selects devices without listing them.
+  pub filter: DeviceActionFilter,
+  /// The name of the service the state belongs to.
+  pub service_name: String,
+  /// The name of the state to move the devices to.
+  pub state_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The code of the service plan to assign, if the state activates the devices.
+  pub service_plan: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The ZIP code the MDNs are assigned from, if the state activates the devices.
+  pub mdn_zip_code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The name of the pool of IP addresses to assign static IPs from.
+  pub carrier_ip_pool_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The Stock Keeping Unit (SKU) of a 4G device type.
+  pub sku_number: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// [`PlaceOfUse`]: The customer name and address where the devices are primarily used.
+  pub primary_place_of_use: Option<PlaceOfUse>,
+}
+
+impl Default for GoToStateRequest {
+  fn default() -> GoToStateRequest {
+    GoToStateRequest {
+      account_name: String::with_capacity(32),
+      devices: Vec::new(),
+      filter: DeviceActionFilter::default(),
+      service_name: String::with_capacity(32),
+      state_name: String::with_capacity(32),
+      service_plan: Option::default(),
+      mdn_zip_code: Option::default(),
+      carrier_ip_pool_name: Option::default(),
+      sku_number: Option::default(),
+      primary_place_of_use: Option::default(),
+    }
+  }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+/// The service state of a device, as reported in [`CarrierInformation::state`].
+///
+/// Parsing ignores case. States this version of the SDK does not know are kept in
+/// [`DeviceState::Unknown`] and sent back as they were received.
+///
+/// [`CarrierInformation::state`]: crate::models::CarrierInformation::state
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::DeviceState;
+///
+/// let state: DeviceState = serde_json::from_str("\"Pending Resume\"").unwrap();
+/// assert_eq!(state, DeviceState::PendingResume);
+/// assert!(state.is_pending());
+///
+/// let state: DeviceState = serde_json::from_str("\"pending mdn change\"").unwrap();
+/// assert_eq!(state, DeviceState::Unknown("pending mdn change".to_string()));
+/// ```
+#[derive(Clone, Debug, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(ascii_case_insensitive)]
+pub enum DeviceState {
+  /// The device has service.
+  #[strum(serialize = "active")]
+  Active,
+  /// Service is suspended and can be restored.
+  #[strum(to_string = "suspend", serialize = "suspended")]
+  Suspended,
+  /// Service is deactivated.
+  #[strum(to_string = "deactive", serialize = "deactivated")]
+  Deactive,
+  /// The device is pre-activated for testing and has not been activated yet.
+  #[strum(to_string = "pre-active", serialize = "preactive")]
+  PreActive,
+  /// An activation request is being processed.
+  #[strum(serialize = "pending activation")]
+  PendingActivation,
+  /// A pre-activation request is being processed.
+  #[strum(serialize = "pending preactive")]
+  PendingPreActive,
+  /// A restore request is being processed.
+  #[strum(serialize = "pending resume")]
+  PendingResume,
+  /// A suspend request is being processed.
+  #[strum(serialize = "pending suspend")]
+  PendingSuspend,
+  /// A deactivation request is being processed.
+  #[strum(serialize = "pending deactivation")]
+  PendingDeactivation,
+  /// A state this version of the SDK does not know.
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl Default for DeviceState {
+  fn default() -> DeviceState {
+    DeviceState::Unknown(String::with_capacity(16))
+  }
+}
+
+impl Serialize for DeviceState {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for DeviceState {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DeviceState, D::Error> {
+    let state = String::deserialize(deserializer)?;
+    // Never fails, unknown states end up in `DeviceState::Unknown`.
+    Ok(DeviceState::from_str(&state).unwrap_or(DeviceState::Unknown(state)))
+  }
+}
+
+impl DeviceState {
+  /// Returns `true` while ThingSpace is still processing a change of state.
+  #[must_use]
+  pub fn is_pending(&self) -> bool {
+    match self {
+      DeviceState::PendingActivation
+      | DeviceState::PendingPreActive
+      | DeviceState::PendingResume
+      | DeviceState::PendingSuspend
+      | DeviceState::PendingDeactivation => true,
+      DeviceState::Unknown(state) => state.to_ascii_lowercase().starts_with("pending"),
+      _ => false,
+    }
+  }
+}
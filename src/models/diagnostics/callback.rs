@@ -0,0 +1,116 @@
+use crate::models::DeviceID;
+use crate::models::timestamp::optional_timestamp;
+use iso8601::DateTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+/// Whether an SCEF device can currently receive data.
+///
+/// Parsing ignores case. Statuses this version of the SDK does not know are kept in
+/// [`ReachabilityStatus::Unknown`] and sent back as they were received.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::ReachabilityStatus;
+///
+/// let status: ReachabilityStatus = serde_json::from_str("\"REACHABLE\"").unwrap();
+/// assert_eq!(status, ReachabilityStatus::Reachable);
+///
+/// let status: ReachabilityStatus = serde_json::from_str("\"Detached\"").unwrap();
+/// assert_eq!(status, ReachabilityStatus::Unknown("Detached".to_string()));
+/// ```
+#[derive(Clone, Debug, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(ascii_case_insensitive)]
+pub enum ReachabilityStatus {
+  /// The device is awake.
+  Reachable,
+  /// The device is in power saving mode or out of coverage.
+  Unreachable,
+  /// A status this version of the SDK does not know.
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl Default for ReachabilityStatus {
+  fn default() -> ReachabilityStatus {
+    ReachabilityStatus::Unknown(String::with_capacity(16))
+  }
+}
+
+impl Serialize for ReachabilityStatus {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for ReachabilityStatus {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ReachabilityStatus, D::Error> {
+    let status = String::deserialize(deserializer)?;
+    // Never fails, unknown statuses end up in `ReachabilityStatus::Unknown`.
+    Ok(ReachabilityStatus::from_str(&status).unwrap_or(ReachabilityStatus::Unknown(status)))
+  }
+}
+
+/// A struct containing a `DiagnosticsService` Callback, sent for reachability subscriptions and
+/// reachability status requests.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::DiagnosticsCallback;
+///
+/// let body = r#"{
+///   "requestId": "595f5c44-c31c-4552-8670-020a1545a84d",
+///   "deviceIds": [{ "id": "990013907835573", "kind": "imei" }],
+///   "reachabilityStatus": "Reachable",
+///   "eventTime": "2024-05-01T12:00:00Z"
+/// }"#;
+///
+/// let callback: DiagnosticsCallback = serde_json::from_str(body).unwrap();
+/// assert!(callback.is_reachable());
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DiagnosticsCallback {
+  /// The requestId returned by the subscription or status request.
+  pub request_id: String,
+  /// Array of [`DeviceID`]s
+  pub device_ids: Vec<DeviceID>,
+  /// [`ReachabilityStatus`]: Whether the device can currently receive data.
+  pub reachability_status: ReachabilityStatus,
+  #[serde(deserialize_with = "optional_timestamp::deserialize")]
+  /// When the device became reachable or unreachable, `None` if ThingSpace sent no timestamp or
+  /// one that could not be parsed.
+  pub event_time: Option<DateTime>,
+  /// The outcome of a subscription request. Valid values include: Success, Failed
+  pub status: Option<String>,
+  /// This displays only if the status is Failed.
+  pub reason: Option<String>,
+  /// Total number of callback requests.
+  pub callback_count: i32,
+  /// Maximum number of callbacks allowed.
+  pub max_callback_threshold: i32,
+}
+
+impl Default for DiagnosticsCallback {
+  fn default() -> Self {
+    DiagnosticsCallback {
+      request_id: String::with_capacity(36),
+      device_ids: vec![DeviceID::default()],
+      reachability_status: ReachabilityStatus::default(),
+      event_time: None,
+      status: None,
+      reason: None,
+      callback_count: i32::default(),
+      max_callback_threshold: i32::default(),
+    }
+  }
+}
+
+impl DiagnosticsCallback {
+  /// Returns `true` if the device is awake and can receive data, e.g. a queued NIDD message.
+  #[must_use]
+  pub fn is_reachable(&self) -> bool {
+    self.reachability_status == ReachabilityStatus::Reachable
+  }
+}
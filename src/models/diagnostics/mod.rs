@@ -0,0 +1,8 @@
+mod reachability;
+pub use reachability::ReachabilityStatusRequest;
+pub use reachability::ReachabilitySubscriptionRequest;
+pub use reachability::ReachabilityType;
+
+mod callback;
+pub use callback::DiagnosticsCallback;
+pub use callback::ReachabilityStatus;
@@ -0,0 +1,72 @@
+use crate::models::DeviceID;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The kind of traffic a device has to be reachable for.
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, Display, EnumString, Serialize, PartialEq, Eq,
+)]
+pub enum ReachabilityType {
+  /// Reachable for data, including NIDD messages.
+  #[default]
+  Data,
+  /// Reachable for SMS messages.
+  #[serde(rename = "SMS")]
+  #[strum(serialize = "SMS")]
+  Sms,
+}
+
+/// A struct containing a request to be notified whenever SCEF devices wake up or go to sleep.
+/// Notifications are sent to the
+/// [`DiagnosticsService`](crate::models::ServiceName::DiagnosticsService) callback listener.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReachabilitySubscriptionRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// Array of [`DeviceID`]s to monitor.
+  pub device_ids: Vec<DeviceID>,
+  /// [`ReachabilityType`]: The kind of traffic to monitor reachability for.
+  pub reachability_type: ReachabilityType,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The longest time, in seconds, a device may stay unreachable before a notification is sent.
+  pub maximum_latency: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// How long the subscription lasts, in seconds.
+  pub monitoring_duration: Option<u32>,
+}
+
+impl Default for ReachabilitySubscriptionRequest {
+  fn default() -> ReachabilitySubscriptionRequest {
+    ReachabilitySubscriptionRequest {
+      account_name: String::with_capacity(32),
+      device_ids: vec![DeviceID::default()],
+      reachability_type: ReachabilityType::default(),
+      maximum_latency: Option::default(),
+      monitoring_duration: Option::default(),
+    }
+  }
+}
+
+/// A struct containing a request for the current reachability of SCEF devices, or for the end of
+/// a reachability subscription. The result is sent to the
+/// [`DiagnosticsService`](crate::models::ServiceName::DiagnosticsService) callback listener.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReachabilityStatusRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// Array of [`DeviceID`]s to query.
+  pub device_ids: Vec<DeviceID>,
+}
+
+impl Default for ReachabilityStatusRequest {
+  fn default() -> ReachabilityStatusRequest {
+    ReachabilityStatusRequest {
+      account_name: String::with_capacity(32),
+      device_ids: vec![DeviceID::default()],
+    }
+  }
+}
@@ -1,5 +1,16 @@
+use crate::models::DeviceIdKind;
 use serde::{Deserialize, Serialize};
 use std::{error, fmt};
+use strum::{Display, EnumString};
+
+/// Response headers kept in a [`ResponseError`]. Everything else is dropped.
+pub const KEPT_ERROR_HEADERS: [&str; 5] = [
+  "content-type",
+  "retry-after",
+  "www-authenticate",
+  "x-request-id",
+  "x-correlation-id",
+];
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CredentialError {
@@ -19,6 +30,230 @@ pub struct ThingSpaceError {
   pub error_message: String,
 }
 
+impl ThingSpaceError {
+  /// Returns `true` if the error code means the OAuth2 access token or the M2M session token
+  /// (`VZ-M2M-Token`) is expired or no longer valid.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::models::ThingSpaceError;
+  ///
+  /// let error = ThingSpaceError {
+  ///   error_code: "UnifiedWebService.REQUEST_FAILED.SessionToken.Expired".to_string(),
+  ///   error_message: "SessionToken Expired".to_string(),
+  /// };
+  /// assert!(error.is_auth_error());
+  /// ```
+  #[must_use]
+  pub fn is_auth_error(&self) -> bool {
+    const AUTH_ERROR_CODES: [&str; 5] = [
+      "sessiontoken",
+      "token.expired",
+      "token.invalid",
+      "invalid_token",
+      "unauthorized",
+    ];
+
+    let code = self.error_code.to_ascii_lowercase();
+    AUTH_ERROR_CODES.iter().any(|c| code.contains(c))
+  }
+
+  /// Maps the error code to an [`ErrorKind`]. Returns [`ErrorKind::Unknown`] if the code is not
+  /// recognized.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::models::{ErrorKind, ThingSpaceError};
+  ///
+  /// let error = ThingSpaceError {
+  ///   error_code: "DEVICE_NOT_FOUND".to_string(),
+  ///   error_message: "Device not found".to_string(),
+  /// };
+  /// assert_eq!(error.kind(), ErrorKind::InvalidDevice);
+  /// ```
+  #[must_use]
+  pub fn kind(&self) -> ErrorKind {
+    const RATE_LIMITED: [&str; 4] = ["ratelimit", "rate_limit", "throttl", "toomanyrequests"];
+    const NOT_FOUND: [&str; 3] = ["notfound", "not_found", "not.found"];
+    const VALIDATION: [&str; 6] = [
+      "invalid",
+      "validation",
+      "missing",
+      "required",
+      "badrequest",
+      "bad_request",
+    ];
+
+    let code = self.error_code.to_ascii_lowercase();
+    let matches = |codes: &[&str]| codes.iter().any(|c| code.contains(c));
+
+    if self.is_auth_error() {
+      ErrorKind::AuthExpired
+    } else if matches(&RATE_LIMITED) {
+      ErrorKind::RateLimited
+    } else if code.contains("device") && (matches(&NOT_FOUND) || matches(&VALIDATION)) {
+      ErrorKind::InvalidDevice
+    } else if matches(&NOT_FOUND) {
+      ErrorKind::NotFound
+    } else if matches(&VALIDATION) {
+      ErrorKind::Validation
+    } else {
+      ErrorKind::Unknown
+    }
+  }
+}
+
+/// The kind of failure behind a [`ResponseError`], so callers can branch on it instead of
+/// matching error code strings.
+#[derive(Clone, Copy, Debug, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorKind {
+  /// The OAuth2 access token or M2M session token expired or is no longer valid.
+  AuthExpired,
+  /// The public/private key pair or the username/password was rejected.
+  Unauthorized,
+  /// The account is not allowed to make the request.
+  Forbidden,
+  /// A device identifier in the request is invalid or unknown.
+  InvalidDevice,
+  /// The requested resource does not exist.
+  NotFound,
+  /// The request was malformed or failed validation.
+  Validation,
+  /// Too many requests were sent.
+  RateLimited,
+  /// ThingSpace failed to process a valid request.
+  Server,
+  /// Any other error.
+  Unknown,
+}
+
+/// A 4xx or 5xx response from ThingSpace.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResponseError {
+  /// The HTTP status code.
+  pub status: u16,
+  /// The response headers listed in [`KEPT_ERROR_HEADERS`], with lowercase names.
+  pub headers: Vec<(String, String)>,
+  /// The raw response body. Invalid UTF-8 is replaced.
+  pub body: String,
+  /// The body, if it is a ThingSpace `errorCode`/`errorMessage` object.
+  pub thingspace: Option<ThingSpaceError>,
+  /// The body, if it is an OAuth2 `error`/`error_description` object.
+  pub credential: Option<CredentialError>,
+}
+
+impl ResponseError {
+  /// Creates a [`ResponseError`] from a response's status, headers and body.
+  ///
+  /// # Example
+  /// ```rust
+  /// use thingspace_sdk::models::{ErrorKind, ResponseError};
+  ///
+  /// let headers = vec![("Retry-After".to_string(), "30".to_string())];
+  /// let error = ResponseError::new(429, &headers, b"<html>Too Many Requests</html>");
+  /// assert_eq!(error.kind(), ErrorKind::RateLimited);
+  /// assert_eq!(error.header("retry-after"), Some("30"));
+  /// assert!(error.thingspace.is_none());
+  /// ```
+  #[must_use]
+  pub fn new(status: u16, headers: &[(String, String)], body: &[u8]) -> ResponseError {
+    let thingspace = serde_json::from_slice::<ThingSpaceError>(body).ok();
+    let credential = match thingspace {
+      Some(_) => None,
+      None => serde_json::from_slice::<CredentialError>(body).ok(),
+    };
+
+    ResponseError {
+      status,
+      headers: headers
+        .iter()
+        .filter(|(n, _)| KEPT_ERROR_HEADERS.iter().any(|k| n.eq_ignore_ascii_case(k)))
+        .map(|(n, v)| (n.to_ascii_lowercase(), v.clone()))
+        .collect(),
+      body: String::from_utf8_lossy(body).into_owned(),
+      thingspace,
+      credential,
+    }
+  }
+
+  /// Returns the value of the header `name`, ignoring case.
+  #[must_use]
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(n, _)| n.eq_ignore_ascii_case(name))
+      .map(|(_, v)| v.as_str())
+  }
+
+  /// The [`ErrorKind`] of the ThingSpace error code if it is recognized, otherwise the kind implied
+  /// by the OAuth2 error or the HTTP status.
+  #[must_use]
+  pub fn kind(&self) -> ErrorKind {
+    if let Some(kind) = self
+      .thingspace
+      .as_ref()
+      .map(ThingSpaceError::kind)
+      .filter(|k| *k != ErrorKind::Unknown)
+    {
+      return kind;
+    }
+
+    if let Some(e) = &self.credential {
+      return match e.error.as_str() {
+        "invalid_token" | "expired_token" => ErrorKind::AuthExpired,
+        _ => ErrorKind::Unauthorized,
+      };
+    }
+
+    match self.status {
+      401 => ErrorKind::AuthExpired,
+      403 => ErrorKind::Forbidden,
+      404 => ErrorKind::NotFound,
+      400 | 422 => ErrorKind::Validation,
+      429 => ErrorKind::RateLimited,
+      500..600 => ErrorKind::Server,
+      _ => ErrorKind::Unknown,
+    }
+  }
+}
+
+impl fmt::Display for ResponseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "\"status\": {}, \"kind\": \"{}\", ",
+      self.status,
+      self.kind()
+    )?;
+    match (&self.thingspace, &self.credential) {
+      (Some(e), _) => write!(f, "\"{}\": \"{}\"", e.error_code, e.error_message),
+      (None, Some(e)) => write!(f, "\"{}\": \"{}\"", e.error, e.error_description),
+      (None, None) => write!(f, "\"body\": {:?}", self.body),
+    }
+  }
+}
+
+/// A device identifier that was rejected before it was sent to ThingSpace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidDeviceId {
+  /// The kind the identifier was checked against.
+  pub kind: DeviceIdKind,
+  /// The rejected identifier.
+  pub id: String,
+  /// Why the identifier was rejected.
+  pub reason: &'static str,
+}
+
+impl fmt::Display for InvalidDeviceId {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "\"{}\": \"{:?} {}\"", self.kind, self.id, self.reason)
+  }
+}
+
+impl error::Error for InvalidDeviceId {}
+
 #[derive(Debug)]
 pub enum Error {
   #[cfg(any(feature = "wasm", feature = "worker"))]
@@ -32,8 +267,8 @@ pub enum Error {
   #[cfg(feature = "reqwest")]
   Reqwest(reqwest::Error),
   Serde(serde_json::Error),
-  Credential(CredentialError),
-  ThingSpace(ThingSpaceError),
+  Response(Box<ResponseError>),
+  InvalidDeviceId(InvalidDeviceId),
   UTF8(std::str::Utf8Error),
 }
 
@@ -51,20 +286,43 @@ impl fmt::Display for Error {
       #[cfg(feature = "reqwest")]
       Error::Reqwest(e) => ("ReqwestError", e.to_string()),
       Error::Serde(e) => ("SerdeError", e.to_string()),
-      Error::Credential(e) => (
-        "CredentialError",
-        format!("\"{}\": \"{}\"", &e.error, &e.error_description),
-      ),
-      Error::ThingSpace(e) => (
-        "ThingSpaceError",
-        format!("\"{}\": \"{}\"", &e.error_code, &e.error_message),
-      ),
+      Error::Response(e) => ("ResponseError", e.to_string()),
+      Error::InvalidDeviceId(e) => ("InvalidDeviceId", e.to_string()),
       Error::UTF8(e) => ("Utf8Error", e.to_string()),
     };
     write!(f, "{{ \"{module}\": {{ {e} }} }}")
   }
 }
 
+impl Error {
+  /// Returns `true` if ThingSpace rejected the request's OAuth2 access token or M2M session
+  /// token, meaning the request may succeed after logging in again.
+  #[must_use]
+  pub fn is_auth_error(&self) -> bool {
+    self.kind() == Some(ErrorKind::AuthExpired)
+  }
+
+  /// The [`ErrorKind`] of a [`Error::Response`], or `None` if no error response was received.
+  /// An [`Error::InvalidDeviceId`] is [`ErrorKind::InvalidDevice`].
+  #[must_use]
+  pub fn kind(&self) -> Option<ErrorKind> {
+    match self {
+      Error::Response(e) => Some(e.kind()),
+      Error::InvalidDeviceId(_) => Some(ErrorKind::InvalidDevice),
+      _ => None,
+    }
+  }
+
+  /// The [`ResponseError`], if ThingSpace answered with a 4xx or 5xx response.
+  #[must_use]
+  pub fn response(&self) -> Option<&ResponseError> {
+    match self {
+      Error::Response(e) => Some(e.as_ref()),
+      _ => None,
+    }
+  }
+}
+
 impl error::Error for Error {
   fn source(&self) -> Option<&(dyn error::Error + 'static)> {
     Some(match self {
@@ -79,8 +337,8 @@ impl error::Error for Error {
       #[cfg(feature = "reqwest")]
       Error::Reqwest(e) => e,
       Error::Serde(e) => e,
-      Error::Credential(_) => return None,
-      Error::ThingSpace(_) => return None,
+      Error::Response(_) => return None,
+      Error::InvalidDeviceId(e) => e,
       Error::UTF8(e) => e,
     })
   }
@@ -133,15 +391,15 @@ impl From<reqwest::Error> for Error {
   }
 }
 
-impl From<CredentialError> for Error {
-  fn from(e: CredentialError) -> Self {
-    Error::Credential(e)
+impl From<InvalidDeviceId> for Error {
+  fn from(e: InvalidDeviceId) -> Self {
+    Error::InvalidDeviceId(e)
   }
 }
 
-impl From<ThingSpaceError> for Error {
-  fn from(e: ThingSpaceError) -> Self {
-    Error::ThingSpace(e)
+impl From<ResponseError> for Error {
+  fn from(e: ResponseError) -> Self {
+    Error::Response(Box::new(e))
   }
 }
 
@@ -306,3 +564,129 @@ impl From<ThingSpaceError> for Error {
 //   uri.push_str(param);
 //   uri.push_str(value);
 // }
+
+#[cfg(test)]
+mod tests {
+  use super::{Error, ErrorKind, InvalidDeviceId, ResponseError, ThingSpaceError};
+  use crate::models::DeviceIdKind;
+
+  fn thingspace(code: &str) -> ThingSpaceError {
+    ThingSpaceError {
+      error_code: code.to_string(),
+      error_message: String::new(),
+    }
+  }
+
+  fn response(status: u16, body: &str) -> ResponseError {
+    ResponseError::new(status, &[], body.as_bytes())
+  }
+
+  #[test]
+  fn thingspace_codes() {
+    for (code, kind) in [
+      (
+        "UnifiedWebService.REQUEST_FAILED.SessionToken.Expired",
+        ErrorKind::AuthExpired,
+      ),
+      (
+        "UnifiedWebService.REQUEST_FAILED.Token.Invalid",
+        ErrorKind::AuthExpired,
+      ),
+      ("REQUEST_FAILED.UnAuthorized", ErrorKind::AuthExpired),
+      ("REQUEST_FAILED.RateLimitExceeded", ErrorKind::RateLimited),
+      ("Throttled", ErrorKind::RateLimited),
+      ("DEVICE_NOT_FOUND", ErrorKind::InvalidDevice),
+      ("REQUEST_FAILED.InvalidDeviceId", ErrorKind::InvalidDevice),
+      ("ACCOUNT_NOT_FOUND", ErrorKind::NotFound),
+      ("REQUEST_FAILED.ServicePlan.Invalid", ErrorKind::Validation),
+      ("MISSING_REQUIRED_FIELD", ErrorKind::Validation),
+      ("DeviceAlreadyActive", ErrorKind::Unknown),
+      ("", ErrorKind::Unknown),
+    ] {
+      assert_eq!(thingspace(code).kind(), kind, "{code}");
+    }
+  }
+
+  #[test]
+  fn auth_errors_win_over_other_matches() {
+    // Contains "invalid" as well, but an invalid token is an auth error.
+    assert!(thingspace("invalid_token").is_auth_error());
+    assert_eq!(thingspace("invalid_token").kind(), ErrorKind::AuthExpired);
+    assert!(!thingspace("DEVICE_NOT_FOUND").is_auth_error());
+  }
+
+  #[test]
+  fn known_thingspace_codes_win_over_the_status() {
+    let error = response(
+      400,
+      r#"{"errorCode":"DEVICE_NOT_FOUND","errorMessage":"x"}"#,
+    );
+    assert_eq!(error.kind(), ErrorKind::InvalidDevice);
+
+    // An unrecognized code falls back to the status.
+    let error = response(503, r#"{"errorCode":"SOMETHING_ELSE","errorMessage":"x"}"#);
+    assert!(error.thingspace.is_some());
+    assert_eq!(error.kind(), ErrorKind::Server);
+  }
+
+  #[test]
+  fn oauth2_errors() {
+    let error = response(401, r#"{"error":"invalid_client","error_description":"x"}"#);
+    assert!(error.thingspace.is_none());
+    assert_eq!(error.kind(), ErrorKind::Unauthorized);
+
+    let error = response(401, r#"{"error":"expired_token","error_description":"x"}"#);
+    assert_eq!(error.kind(), ErrorKind::AuthExpired);
+  }
+
+  #[test]
+  fn statuses_without_a_known_body() {
+    for (status, kind) in [
+      (401, ErrorKind::AuthExpired),
+      (403, ErrorKind::Forbidden),
+      (404, ErrorKind::NotFound),
+      (400, ErrorKind::Validation),
+      (422, ErrorKind::Validation),
+      (429, ErrorKind::RateLimited),
+      (500, ErrorKind::Server),
+      (504, ErrorKind::Server),
+      (409, ErrorKind::Unknown),
+    ] {
+      assert_eq!(response(status, "<html></html>").kind(), kind, "{status}");
+    }
+  }
+
+  #[test]
+  fn kept_headers() {
+    let headers = [
+      ("Retry-After".to_string(), "30".to_string()),
+      ("Set-Cookie".to_string(), "secret".to_string()),
+    ];
+    let error = ResponseError::new(429, &headers, b"");
+    assert_eq!(
+      error.headers,
+      [("retry-after".to_string(), "30".to_string())]
+    );
+    assert_eq!(error.header("RETRY-AFTER"), Some("30"));
+  }
+
+  #[test]
+  fn error_kind() {
+    let error = Error::from(response(401, ""));
+    assert_eq!(error.kind(), Some(ErrorKind::AuthExpired));
+    assert!(error.is_auth_error());
+    assert_eq!(error.response().map(|e| e.status), Some(401));
+
+    let error = Error::from(InvalidDeviceId {
+      kind: DeviceIdKind::Imei,
+      id: "1".to_string(),
+      reason: "must be 15 digits, or 16 for an IMEISV",
+    });
+    assert_eq!(error.kind(), Some(ErrorKind::InvalidDevice));
+    assert!(error.response().is_none());
+
+    let error = Error::from(serde_json::from_str::<u8>("").unwrap_err());
+    assert_eq!(error.kind(), None);
+    assert!(!error.is_auth_error());
+  }
+}
@@ -0,0 +1,52 @@
+use crate::models::timestamp::optional_timestamp;
+use crate::models::{DeviceID, ExtendedAttribute};
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request for the connection history of a single device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConnectionHistoryRequest {
+  /// [`DeviceID`]: The device to list connection events for.
+  pub device_id: DeviceID,
+  /// Only include events that occurred at or after this date and time.
+  pub earliest: DateTime,
+  /// Only include events that occurred before this date and time.
+  pub latest: DateTime,
+}
+
+/// A struct containing one page of the connection history of a device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConnectionHistoryResponse {
+  /// `true` if there are more events than were returned.
+  pub has_more_data: bool,
+  /// Array of [`ConnectionEvent`]s, oldest first.
+  pub connection_history: Vec<ConnectionEvent>,
+}
+
+/// A single connection or disconnection of a device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConnectionEvent {
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// When the event occurred, `None` if ThingSpace sent no timestamp or one that could not be
+  /// parsed.
+  pub occurred_at: Option<DateTime>,
+  /// Details of the event as key and value pairs, e.g. `Event` and `BytesUsed`.
+  pub connection_event_attributes: Vec<ExtendedAttribute>,
+  /// Array of [`ExtendedAttribute`]s
+  pub extended_attributes: Vec<ExtendedAttribute>,
+}
+
+impl ConnectionEvent {
+  /// The value of the connection event attribute `key`, if present.
+  #[must_use]
+  pub fn attribute(&self, key: &str) -> Option<&str> {
+    self
+      .connection_event_attributes
+      .iter()
+      .find(|attribute| attribute.key == key)
+      .and_then(|attribute| attribute.value.as_deref())
+  }
+}
@@ -0,0 +1,9 @@
+mod connection;
+pub use connection::ConnectionEvent;
+pub use connection::ConnectionHistoryRequest;
+pub use connection::ConnectionHistoryResponse;
+
+mod provisioning;
+pub use provisioning::ProvisioningEvent;
+pub use provisioning::ProvisioningHistoryRequest;
+pub use provisioning::ProvisioningHistoryResponse;
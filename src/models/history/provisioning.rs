@@ -0,0 +1,50 @@
+use crate::models::timestamp::optional_timestamp;
+use crate::models::{DeviceID, ExtendedAttribute};
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request for the provisioning history of a single device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProvisioningHistoryRequest {
+  /// [`DeviceID`]: The device to list provisioning events for.
+  pub device_id: DeviceID,
+  /// Only include events that occurred at or after this date and time.
+  pub earliest: DateTime,
+  /// Only include events that occurred before this date and time.
+  pub latest: DateTime,
+}
+
+/// A struct containing one page of the provisioning history of a device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProvisioningHistoryResponse {
+  /// `true` if there are more events than were returned.
+  pub has_more_data: bool,
+  /// Array of [`ProvisioningEvent`]s, oldest first.
+  pub device_provisioning_history: Vec<ProvisioningEvent>,
+}
+
+/// A single change to the provisioning of a device, such as an activation or a plan change.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProvisioningEvent {
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// When the event occurred, `None` if ThingSpace sent no timestamp or one that could not be
+  /// parsed.
+  pub occurred_at: Option<DateTime>,
+  /// The outcome of the event, e.g. `Success`.
+  pub status: String,
+  /// The user or application that caused the event.
+  pub event_by: String,
+  /// The type of the event, e.g. `Activate` or `Suspend`.
+  pub event_type: String,
+  /// The service plan of the device after the event.
+  pub service_plan: String,
+  /// The MDN of the device after the event.
+  pub mdn: String,
+  /// The MSISDN of the device after the event.
+  pub msisdn: String,
+  /// Array of [`ExtendedAttribute`]s
+  pub extended_attributes: Vec<ExtendedAttribute>,
+}
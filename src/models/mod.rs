@@ -1,29 +1,116 @@
 mod error;
+pub use error::CredentialError;
 pub use error::Error;
+pub use error::ErrorKind;
+pub use error::InvalidDeviceId;
+pub use error::KEPT_ERROR_HEADERS;
+pub use error::ResponseError;
 pub use error::ThingSpaceError;
 
 mod login;
 pub use login::LoginResponse;
 
+mod secrets;
+pub use secrets::Secrets;
+
+pub(crate) mod timestamp;
+pub use timestamp::format_timestamp;
+pub use timestamp::parse_timestamp;
+
 mod session;
 pub use session::Session;
 pub use session::SessionRequestBody;
 
+mod account;
+pub use account::AccountInformation;
+pub use account::AccountState;
+pub use account::AccountStatesResponse;
+pub use account::IpPool;
+pub use account::ServicePlan;
+pub use account::ServiceStates;
+
 mod devices;
 pub use devices::AccountDeviceListRequest;
 pub use devices::AccountDeviceListResponse;
+pub use devices::ActionResponse;
+pub use devices::ActivateDevicesRequest;
+pub use devices::Address;
+pub use devices::ChangeServicePlanRequest;
+pub use devices::CustomField;
+pub use devices::CustomFieldKey;
+pub use devices::CustomerName;
+pub use devices::DeactivateDevicesRequest;
+pub use devices::DeleteDevicesRequest;
 pub use devices::Device;
+pub use devices::DeviceActionFilter;
+pub use devices::DeviceAttributes;
 pub use devices::DeviceID;
+pub use devices::DeviceIdKind;
+pub use devices::DeviceIdList;
+pub use devices::DeviceState;
+pub use devices::DeviceStateRequest;
+pub use devices::ExtendedAttribute;
+pub use devices::GoToStateRequest;
+pub use devices::PlaceOfUse;
+pub use devices::UpdateCustomFieldsRequest;
+
+mod device_group;
+pub use device_group::CreateDeviceGroupRequest;
+pub use device_group::DeviceGroup;
+pub use device_group::DeviceGroupInformation;
+pub use device_group::DeviceGroupPageRequest;
+pub use device_group::SuccessResponse;
+pub use device_group::UpdateDeviceGroupRequest;
+
+mod diagnostics;
+pub use diagnostics::DiagnosticsCallback;
+pub use diagnostics::ReachabilityStatus;
+pub use diagnostics::ReachabilityStatusRequest;
+pub use diagnostics::ReachabilitySubscriptionRequest;
+pub use diagnostics::ReachabilityType;
 
 mod nidd;
 pub use nidd::NiddCallback;
+pub use nidd::NiddConfiguration;
+pub use nidd::NiddConfigurationListRequest;
+pub use nidd::NiddConfigurationRequest;
 pub use nidd::NiddMessage;
 pub use nidd::NiddRequest;
 pub use nidd::NiddResponse;
 
+mod history;
+pub use history::ConnectionEvent;
+pub use history::ConnectionHistoryRequest;
+pub use history::ConnectionHistoryResponse;
+pub use history::ProvisioningEvent;
+pub use history::ProvisioningHistoryRequest;
+pub use history::ProvisioningHistoryResponse;
+
+mod usage;
+pub use usage::AggregateUsageCallback;
+pub use usage::AggregateUsageRequest;
+pub use usage::AggregateUsageResponse;
+pub use usage::DeviceUsageRequest;
+pub use usage::DeviceUsageResponse;
+pub use usage::DeviceUsageSummary;
+pub use usage::UsageRecord;
+pub use usage::UsageTotals;
+
+mod sms;
+pub use sms::SendSmsRequest;
+pub use sms::SmsCallback;
+pub use sms::SmsDataEncoding;
+pub use sms::SmsDeliveryConfirmation;
+pub use sms::SmsHistoryResponse;
+pub use sms::SmsMessage;
+
 mod registered_callback_listener;
 pub use registered_callback_listener::CallbackListener;
 pub use registered_callback_listener::CallbackListenerResponse;
 
+mod request_status;
+pub use request_status::RequestStatus;
+pub use request_status::RequestStatusResponse;
+
 mod service_name;
 pub use service_name::ServiceName;
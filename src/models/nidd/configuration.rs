@@ -0,0 +1,71 @@
+use crate::models::DeviceID;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request to set up or change the NIDD configuration of NB-IoT devices.
+/// The result is sent to the [`NiddService`](crate::models::ServiceName::NiddService) callback
+/// listener.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NiddConfigurationRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// Array of [`DeviceID`]s to configure.
+  pub device_ids: Vec<DeviceID>,
+  /// The Access Point Name used for NIDD messages.
+  pub apn: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The largest NIDD message the devices accept, in bytes.
+  pub maximum_packet_size: Option<u32>,
+  /// Acknowledge messages at the Reliable Data Service layer.
+  pub reliable_data_service: bool,
+}
+
+impl Default for NiddConfigurationRequest {
+  fn default() -> NiddConfigurationRequest {
+    NiddConfigurationRequest {
+      account_name: String::with_capacity(32),
+      device_ids: vec![DeviceID::default()],
+      apn: String::with_capacity(32),
+      maximum_packet_size: Option::default(),
+      reliable_data_service: bool::default(),
+    }
+  }
+}
+
+/// A struct containing a request for the NIDD configuration of NB-IoT devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NiddConfigurationListRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// Array of [`DeviceID`]s to return the configuration of.
+  pub device_ids: Vec<DeviceID>,
+}
+
+impl Default for NiddConfigurationListRequest {
+  fn default() -> NiddConfigurationListRequest {
+    NiddConfigurationListRequest {
+      account_name: String::with_capacity(32),
+      device_ids: vec![DeviceID::default()],
+    }
+  }
+}
+
+/// The NIDD configuration of a single device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NiddConfiguration {
+  /// Array of [`DeviceID`]s of the device.
+  pub device_ids: Vec<DeviceID>,
+  /// The Access Point Name used for NIDD messages.
+  pub apn: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// The largest NIDD message the device accepts, in bytes.
+  pub maximum_packet_size: Option<u32>,
+  /// `true` if messages are acknowledged at the Reliable Data Service layer.
+  pub reliable_data_service: bool,
+  /// Valid values include: Configured, Pending, Failed
+  pub status: String,
+}
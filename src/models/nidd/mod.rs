@@ -9,3 +9,8 @@ pub use callback::NiddCallback;
 
 mod response;
 pub use response::NiddResponse;
+
+mod configuration;
+pub use configuration::NiddConfiguration;
+pub use configuration::NiddConfigurationListRequest;
+pub use configuration::NiddConfigurationRequest;
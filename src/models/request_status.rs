@@ -0,0 +1,87 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+use strum::{Display, EnumString};
+
+/// The processing state of an asynchronous ThingSpace request.
+///
+/// Parsing ignores case. Statuses this version of the SDK does not know are kept in
+/// [`RequestStatus::Unknown`] and sent back as they were received.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::RequestStatus;
+///
+/// let status: RequestStatus = serde_json::from_str("\"InProgress\"").unwrap();
+/// assert_eq!(status, RequestStatus::InProgress);
+///
+/// let status: RequestStatus = serde_json::from_str("\"SUCCESS\"").unwrap();
+/// assert!(status.is_terminal());
+///
+/// let status: RequestStatus = serde_json::from_str("\"Throttled\"").unwrap();
+/// assert_eq!(status, RequestStatus::Unknown("Throttled".to_string()));
+/// ```
+#[derive(Clone, Debug, Default, Display, EnumString, PartialEq, Eq, Hash)]
+#[strum(ascii_case_insensitive)]
+pub enum RequestStatus {
+  /// The request was accepted but has not been processed yet.
+  #[default]
+  Pending,
+  /// The request is waiting for the carrier.
+  Queued,
+  /// The request is being processed.
+  InProgress,
+  /// The request completed successfully.
+  Success,
+  /// The request failed.
+  Failed,
+  /// The request was cancelled.
+  Cancelled,
+  /// A status this version of the SDK does not know.
+  #[strum(default)]
+  Unknown(String),
+}
+
+impl Serialize for RequestStatus {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for RequestStatus {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RequestStatus, D::Error> {
+    let status = String::deserialize(deserializer)?;
+    // Never fails, unknown statuses end up in `RequestStatus::Unknown`.
+    Ok(RequestStatus::from_str(&status).unwrap_or(RequestStatus::Unknown(status)))
+  }
+}
+
+impl RequestStatus {
+  /// Returns `true` once the request has finished and its status will not change again.
+  #[must_use]
+  pub fn is_terminal(&self) -> bool {
+    matches!(
+      self,
+      RequestStatus::Success | RequestStatus::Failed | RequestStatus::Cancelled
+    )
+  }
+}
+
+/// A struct containing the status of an asynchronous request, such as a NIDD message or a device
+/// action.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RequestStatusResponse {
+  /// requestId of the async request
+  pub request_id: String,
+  /// [`RequestStatus`]: The processing state of the request.
+  pub status: RequestStatus,
+}
+
+impl Default for RequestStatusResponse {
+  fn default() -> RequestStatusResponse {
+    RequestStatusResponse {
+      request_id: String::with_capacity(36),
+      status: RequestStatus::default(),
+    }
+  }
+}
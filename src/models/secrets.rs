@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A struct containing a user's Verizon account secrets required for API use.
+///
+/// The `Debug` output leaves out the private key and the password.
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct Secrets {
+  /// The ThingSpace application public key.
+  pub public_key: String,
+  /// The ThingSpace application private key.
+  pub private_key: String,
+  /// The ThingSpace UWS username used to start an M2M session.
+  pub username: String,
+  /// The ThingSpace UWS password used to start an M2M session.
+  pub password: String,
+  /// The name of the billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+}
+
+impl fmt::Debug for Secrets {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Secrets")
+      .field("public_key", &self.public_key)
+      .field("private_key", &"<redacted>")
+      .field("username", &self.username)
+      .field("password", &"<redacted>")
+      .field("account_name", &self.account_name)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Secrets;
+
+  #[test]
+  fn debug_redacts_private_key_and_password() {
+    let secrets = Secrets {
+      public_key: "public".to_string(),
+      private_key: "private-key-value".to_string(),
+      username: "user".to_string(),
+      password: "password-value".to_string(),
+      account_name: "0000123456-00001".to_string(),
+    };
+
+    let debug = format!("{secrets:?}");
+    assert!(!debug.contains("private-key-value"));
+    assert!(!debug.contains("password-value"));
+    assert!(debug.contains("0000123456-00001"));
+  }
+}
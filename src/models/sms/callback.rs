@@ -0,0 +1,75 @@
+use crate::models::DeviceID;
+use crate::models::timestamp::optional_timestamp;
+use iso8601::DateTime;
+use serde::Deserialize;
+
+/// A struct containing an `EnhancedConnectivityService` Callback: either an SMS message sent by a
+/// device, or a notification that a message sent with `send_sms` was sent by the network.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SmsCallback {
+  /// The requestId returned by `send_sms`; not set for messages sent by a device.
+  pub request_id: Option<String>,
+  /// Array of [`DeviceID`]s
+  pub device_ids: Vec<DeviceID>,
+  /// The contents of an SMS message sent by a device.
+  pub sms_message: Option<String>,
+  /// Valid values include: Sent, Queued, Failed
+  pub status: Option<String>,
+  #[serde(deserialize_with = "optional_timestamp::deserialize")]
+  /// When the message was sent or received, `None` if ThingSpace sent no timestamp or one that
+  /// could not be parsed.
+  pub timestamp: Option<DateTime>,
+  /// Total number of callback requests.
+  pub callback_count: i32,
+  /// Maximum number of callbacks allowed.
+  pub max_callback_threshold: i32,
+}
+
+impl Default for SmsCallback {
+  fn default() -> Self {
+    SmsCallback {
+      request_id: None,
+      device_ids: vec![DeviceID::default()],
+      sms_message: None,
+      status: None,
+      timestamp: None,
+      callback_count: i32::default(),
+      max_callback_threshold: i32::default(),
+    }
+  }
+}
+
+/// A struct containing an `SMSDeliveryConfirmation` Callback, sent when a device acknowledges a
+/// message sent with `send_sms`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SmsDeliveryConfirmation {
+  /// The requestId returned by `send_sms`.
+  pub request_id: String,
+  /// Array of [`DeviceID`]s
+  pub device_ids: Vec<DeviceID>,
+  /// Valid values include: Delivered, DeliveryFailed
+  pub status: Option<String>,
+  #[serde(deserialize_with = "optional_timestamp::deserialize")]
+  /// When the device acknowledged the message, `None` if ThingSpace sent no timestamp or one that
+  /// could not be parsed.
+  pub delivered_at: Option<DateTime>,
+  /// Total number of callback requests.
+  pub callback_count: i32,
+  /// Maximum number of callbacks allowed.
+  pub max_callback_threshold: i32,
+}
+
+impl Default for SmsDeliveryConfirmation {
+  fn default() -> Self {
+    SmsDeliveryConfirmation {
+      request_id: String::with_capacity(36),
+      device_ids: vec![DeviceID::default()],
+      status: None,
+      delivered_at: None,
+      callback_count: i32::default(),
+      max_callback_threshold: i32::default(),
+    }
+  }
+}
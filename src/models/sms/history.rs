@@ -0,0 +1,54 @@
+use crate::models::DeviceID;
+use crate::models::timestamp::optional_timestamp;
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing the queued SMS messages that devices sent to the account.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SmsHistoryResponse {
+  /// `true` if there are more messages than were returned.
+  pub has_more_data: bool,
+  /// Array of [`SmsMessage`]s, oldest first.
+  pub messages: Vec<SmsMessage>,
+}
+
+/// An SMS message sent by a device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SmsMessage {
+  /// Array of [`DeviceID`]s of the device that sent the message.
+  pub device_ids: Vec<DeviceID>,
+  /// The contents of the message.
+  pub message: String,
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// When the message was received by the network, `None` if ThingSpace sent no timestamp or one
+  /// that could not be parsed.
+  pub timestamp: Option<DateTime>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::SmsHistoryResponse;
+
+  #[test]
+  fn lenient_timestamps() {
+    let body = r#"{
+      "hasMoreData": false,
+      "messages": [
+        { "message": "a", "timestamp": "2018-09-25T22:37:59-04:00" },
+        { "message": "b", "timestamp": "2018-09-25 22:37:59" },
+        { "message": "c", "timestamp": "" },
+        { "message": "d" }
+      ]
+    }"#;
+
+    let response: SmsHistoryResponse = serde_json::from_str(body).unwrap();
+    let parsed: Vec<bool> = response
+      .messages
+      .iter()
+      .map(|message| message.timestamp.is_some())
+      .collect();
+    assert_eq!(parsed, [true, true, false, false]);
+  }
+}
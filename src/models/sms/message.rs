@@ -0,0 +1,59 @@
+use crate::models::DeviceID;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The encoding of an SMS message.
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, Display, EnumString, Serialize, PartialEq, Eq,
+)]
+pub enum SmsDataEncoding {
+  /// 7-bit ASCII text, up to 160 characters.
+  #[default]
+  #[serde(rename = "7-bit ASCII")]
+  #[strum(serialize = "7-bit ASCII")]
+  Ascii,
+  /// 8-bit UTF-8 text, up to 140 bytes.
+  #[serde(rename = "8-bit UTF-8")]
+  #[strum(serialize = "8-bit UTF-8")]
+  Utf8,
+  /// 8-bit binary data, Base64-encoded, up to 140 bytes.
+  #[serde(rename = "8-bit DATA")]
+  #[strum(serialize = "8-bit DATA")]
+  Data,
+}
+
+/// A struct containing an SMS message to send to devices.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SendSmsRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  /// Array of [`DeviceID`]s to send the message to.
+  pub device_ids: Vec<DeviceID>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Send the message to every device in this device group.
+  pub group_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Send the message to every device with this service plan.
+  pub service_plan: Option<String>,
+  /// The message, encoded as given by `data_encoding`.
+  pub sms_message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  /// [`SmsDataEncoding`]: Defaults to 7-bit ASCII.
+  pub data_encoding: Option<SmsDataEncoding>,
+}
+
+impl Default for SendSmsRequest {
+  fn default() -> SendSmsRequest {
+    SendSmsRequest {
+      account_name: String::with_capacity(32),
+      device_ids: Vec::new(),
+      group_name: Option::default(),
+      service_plan: Option::default(),
+      sms_message: String::with_capacity(160),
+      data_encoding: Option::default(),
+    }
+  }
+}
@@ -0,0 +1,11 @@
+mod message;
+pub use message::SendSmsRequest;
+pub use message::SmsDataEncoding;
+
+mod history;
+pub use history::SmsHistoryResponse;
+pub use history::SmsMessage;
+
+mod callback;
+pub use callback::SmsCallback;
+pub use callback::SmsDeliveryConfirmation;
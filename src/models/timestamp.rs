@@ -0,0 +1,182 @@
+use iso8601::{Date, DateTime};
+
+/// Parses a timestamp in one of the formats ThingSpace returns:
+/// ISO 8601 date-times such as `2018-04-27T19:09:10.000Z` or `2018-09-25T22:37:59-04:00`,
+/// date-times separated by a space such as `2018-09-25 22:37:59`, and plain dates such as
+/// `2018-05-17`, which are read as midnight UTC.
+///
+/// Returns `None` for an empty string or any other format.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::parse_timestamp;
+///
+/// let timestamp = parse_timestamp("2018-09-25 22:37:59").unwrap();
+/// assert_eq!(timestamp.time.hour, 22);
+/// assert_eq!(parse_timestamp("2018-05-17").unwrap().time.hour, 0);
+/// assert!(parse_timestamp("").is_none());
+/// ```
+#[must_use]
+pub fn parse_timestamp(value: &str) -> Option<DateTime> {
+  let value = value.trim();
+  if value.is_empty() {
+    return None;
+  }
+
+  if let Ok(timestamp) = iso8601::datetime(value) {
+    return Some(timestamp);
+  }
+
+  if let Some((date, time)) = value.split_once(' ') {
+    return iso8601::datetime(&format!("{date}T{time}")).ok();
+  }
+
+  iso8601::date(value).ok().map(|date| DateTime {
+    date,
+    time: iso8601::Time::default(),
+  })
+}
+
+/// Formats `timestamp` as an RFC 3339 date-time, e.g. `2018-09-25T22:37:59.000-04:00`.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{format_timestamp, parse_timestamp};
+///
+/// let timestamp = parse_timestamp("2018-09-25T22:37:59-04:00").unwrap();
+/// assert_eq!(format_timestamp(&timestamp), "2018-09-25T22:37:59.000-04:00");
+/// ```
+#[must_use]
+pub fn format_timestamp(timestamp: &DateTime) -> String {
+  let Date::YMD { year, month, day } = timestamp.date else {
+    return timestamp.to_string();
+  };
+
+  let time = &timestamp.time;
+  let sign = if time.tz_offset_hours < 0 || time.tz_offset_minutes < 0 {
+    '-'
+  } else {
+    '+'
+  };
+
+  format!(
+    "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}{sign}{:02}:{:02}",
+    time.hour,
+    time.minute,
+    time.second,
+    time.millisecond,
+    time.tz_offset_hours.unsigned_abs(),
+    time.tz_offset_minutes.unsigned_abs(),
+  )
+}
+
+/// Milliseconds since the Unix epoch of `timestamp`, so timestamps in different time zones can be
+/// compared. Returns `None` for week and ordinal dates.
+pub(crate) fn timestamp_millis(timestamp: &DateTime) -> Option<i64> {
+  let Date::YMD { year, month, day } = timestamp.date else {
+    return None;
+  };
+
+  // Days from 1970-01-01 to the civil date, see http://howardhinnant.github.io/date_algorithms.html
+  let year = i64::from(year) - i64::from(month <= 2);
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let month = i64::from(month);
+  let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  let days = era * 146_097 + day_of_era - 719_468;
+
+  let time = &timestamp.time;
+  let seconds = days * 86_400
+    + i64::from(time.hour) * 3_600
+    + i64::from(time.minute) * 60
+    + i64::from(time.second)
+    - i64::from(time.tz_offset_hours) * 3_600
+    - i64::from(time.tz_offset_minutes) * 60;
+
+  Some(seconds * 1_000 + i64::from(time.millisecond))
+}
+
+/// (De)serializes an `Option<DateTime>` with [`parse_timestamp`] and [`format_timestamp`], for
+/// use with `#[serde(with = "...")]`. Timestamps that fail to parse become `None` instead of
+/// failing the whole response.
+pub(crate) mod optional_timestamp {
+  use super::{format_timestamp, parse_timestamp};
+  use iso8601::DateTime;
+  use serde::{Deserialize, Deserializer, Serializer};
+
+  pub(crate) fn serialize<S: Serializer>(
+    timestamp: &Option<DateTime>,
+    serializer: S,
+  ) -> Result<S::Ok, S::Error> {
+    match timestamp {
+      Some(timestamp) => serializer.serialize_str(&format_timestamp(timestamp)),
+      None => serializer.serialize_none(),
+    }
+  }
+
+  pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Option<DateTime>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.as_deref().and_then(parse_timestamp))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{optional_timestamp, parse_timestamp, timestamp_millis};
+  use iso8601::DateTime;
+  use serde::Deserialize;
+
+  fn millis(value: &str) -> Option<i64> {
+    timestamp_millis(&parse_timestamp(value)?)
+  }
+
+  #[test]
+  fn millis_since_the_epoch() {
+    assert_eq!(millis("1970-01-01T00:00:00Z"), Some(0));
+    assert_eq!(millis("1969-12-31T23:59:59Z"), Some(-1_000));
+    assert_eq!(millis("2024-02-29"), Some(1_709_164_800_000));
+    assert_eq!(millis("2018-09-26T02:37:59.123Z"), Some(1_537_929_479_123));
+  }
+
+  #[test]
+  fn offsets_are_subtracted() {
+    assert_eq!(
+      millis("2018-09-25T22:37:59.123-04:00"),
+      millis("2018-09-26T02:37:59.123Z")
+    );
+    assert_eq!(
+      millis("2018-09-26T08:07:59.123+05:30"),
+      millis("2018-09-26T02:37:59.123Z")
+    );
+    // ThingSpace sometimes separates date and time with a space.
+    assert_eq!(
+      millis("2018-09-26 02:37:59.123Z"),
+      millis("2018-09-26T02:37:59.123Z")
+    );
+  }
+
+  #[test]
+  fn week_and_ordinal_dates_have_no_millis() {
+    assert_eq!(millis("2024-W01-1"), None);
+    assert_eq!(millis("2024-060"), None);
+  }
+
+  #[test]
+  fn invalid_timestamps_deserialize_as_none() {
+    #[derive(Deserialize)]
+    struct Event {
+      #[serde(default, with = "optional_timestamp")]
+      at: Option<DateTime>,
+    }
+
+    let parse = |json: &str| serde_json::from_str::<Event>(json).unwrap().at;
+    assert!(parse(r#"{"at":"2024-05-01T10:00:00Z"}"#).is_some());
+    assert!(parse(r#"{"at":"not a date"}"#).is_none());
+    assert!(parse(r#"{"at":""}"#).is_none());
+    assert!(parse(r#"{"at":null}"#).is_none());
+    assert!(parse("{}").is_none());
+  }
+}
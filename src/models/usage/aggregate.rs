@@ -0,0 +1,105 @@
+use super::UsageTotals;
+use crate::models::{DeviceID, DeviceIdKind};
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A struct containing a request for the total usage of one or more devices. The result is sent to
+/// the [`DeviceUsage`](crate::models::ServiceName::DeviceUsage) callback listener as an
+/// [`AggregateUsageCallback`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AggregateUsageRequest {
+  /// The name of a billing account, in the form of 10 digits, a hyphen,
+  /// and then five more digits. Must include any leading zeros.
+  pub account_name: String,
+  /// Array of [`DeviceID`]s to report usage for.
+  pub device_ids: Vec<DeviceID>,
+  /// The start of the reporting period.
+  pub start_time: DateTime,
+  /// The end of the reporting period.
+  pub end_time: DateTime,
+}
+
+impl Default for AggregateUsageRequest {
+  fn default() -> AggregateUsageRequest {
+    AggregateUsageRequest {
+      account_name: String::with_capacity(32),
+      device_ids: vec![DeviceID::default()],
+      start_time: DateTime::default(),
+      end_time: DateTime::default(),
+    }
+  }
+}
+
+/// A struct containing the transaction id of an [`AggregateUsageRequest`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AggregateUsageResponse {
+  /// Transaction id that is returned with the [`AggregateUsageCallback`].
+  pub txid: String,
+}
+
+impl Default for AggregateUsageResponse {
+  fn default() -> AggregateUsageResponse {
+    AggregateUsageResponse {
+      txid: String::with_capacity(36),
+    }
+  }
+}
+
+/// The `DeviceUsage` callback message sent in response to an [`AggregateUsageRequest`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AggregateUsageCallback {
+  /// Transaction id from the [`AggregateUsageResponse`].
+  pub txid: String,
+  /// Array of [`DeviceUsageSummary`]s, one or more per device.
+  pub usage: Vec<DeviceUsageSummary>,
+}
+
+impl AggregateUsageCallback {
+  /// Adds up the bytes, SMS and sessions of each device, keyed by its [`DeviceID`] of the given
+  /// `kind`. Devices without an identifier of that kind are added up under an empty key.
+  #[must_use]
+  pub fn totals_by_device(&self, kind: &DeviceIdKind) -> HashMap<String, UsageTotals> {
+    let mut totals = HashMap::<String, UsageTotals>::new();
+    for summary in &self.usage {
+      let id = summary
+        .device_ids
+        .iter()
+        .find(|device_id| device_id.kind == *kind)
+        .map(|device_id| device_id.id.clone())
+        .unwrap_or_default();
+      *totals.entry(id).or_default() += summary.totals();
+    }
+
+    totals
+  }
+}
+
+/// The total usage of one device over the requested period.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceUsageSummary {
+  /// Array of [`DeviceID`]s of the device.
+  pub device_ids: Vec<DeviceID>,
+  /// Bytes of data sent and received.
+  pub data_usage: u64,
+  /// Number of SMS messages sent and received.
+  pub sms_usage: u64,
+  /// Number of data sessions.
+  pub sessions: u64,
+}
+
+impl DeviceUsageSummary {
+  /// The usage of this device as [`UsageTotals`].
+  #[must_use]
+  pub fn totals(&self) -> UsageTotals {
+    UsageTotals {
+      bytes: self.data_usage,
+      sms: self.sms_usage,
+      sessions: self.sessions,
+    }
+  }
+}
@@ -0,0 +1,58 @@
+use super::UsageTotals;
+use crate::models::DeviceID;
+use crate::models::timestamp::optional_timestamp;
+use iso8601::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A struct containing a request for the usage history of a single device.
+///
+/// Every field is required by ThingSpace, so there is no `Default`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceUsageRequest {
+  /// [`DeviceID`]: The device to report usage for.
+  pub device_id: DeviceID,
+  /// The start of the reporting period.
+  pub earliest: DateTime,
+  /// The end of the reporting period.
+  pub latest: DateTime,
+}
+
+/// A struct containing the usage history of a single device.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DeviceUsageResponse {
+  /// `true` if there are more records than were returned.
+  pub has_more_data: bool,
+  /// Array of [`UsageRecord`]s, one per usage event.
+  pub usage_history: Vec<UsageRecord>,
+}
+
+impl DeviceUsageResponse {
+  /// Adds up the bytes, SMS and sessions of every [`UsageRecord`] in this response only. If
+  /// [`DeviceUsageResponse::has_more_data`] is `true` the totals are incomplete; use
+  /// [`ThingSpaceClient::device_usage_stream`](crate::api::ThingSpaceClient::device_usage_stream)
+  /// to add up every page.
+  #[must_use]
+  pub fn totals(&self) -> UsageTotals {
+    self.usage_history.iter().sum()
+  }
+}
+
+/// A single data or SMS usage event.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UsageRecord {
+  /// Bytes of data sent and received.
+  pub bytes_used: u64,
+  /// Number of SMS messages sent and received.
+  pub sms_used: u64,
+  /// Number of data sessions.
+  pub number_of_sessions: u64,
+  /// The network that reported the usage.
+  pub source: String,
+  #[serde(with = "optional_timestamp", skip_serializing_if = "Option::is_none")]
+  /// When the usage event occurred, `None` if ThingSpace sent no timestamp or one that could not
+  /// be parsed.
+  pub event_date_time: Option<DateTime>,
+}
@@ -0,0 +1,13 @@
+mod list;
+pub use list::DeviceUsageRequest;
+pub use list::DeviceUsageResponse;
+pub use list::UsageRecord;
+
+mod aggregate;
+pub use aggregate::AggregateUsageCallback;
+pub use aggregate::AggregateUsageRequest;
+pub use aggregate::AggregateUsageResponse;
+pub use aggregate::DeviceUsageSummary;
+
+mod totals;
+pub use totals::UsageTotals;
@@ -0,0 +1,85 @@
+use super::UsageRecord;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// Bytes, SMS and sessions added up over a number of usage records.
+///
+/// # Example
+/// ```rust
+/// use thingspace_sdk::models::{UsageRecord, UsageTotals};
+///
+/// let records = [
+///   UsageRecord {
+///     bytes_used: 1024,
+///     number_of_sessions: 1,
+///     ..Default::default()
+///   },
+///   UsageRecord {
+///     bytes_used: 512,
+///     sms_used: 2,
+///     number_of_sessions: 1,
+///     ..Default::default()
+///   },
+/// ];
+///
+/// let totals: UsageTotals = records.iter().sum();
+/// assert_eq!(
+///   totals,
+///   UsageTotals {
+///     bytes: 1536,
+///     sms: 2,
+///     sessions: 2
+///   }
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UsageTotals {
+  /// Bytes of data sent and received.
+  pub bytes: u64,
+  /// Number of SMS messages sent and received.
+  pub sms: u64,
+  /// Number of data sessions.
+  pub sessions: u64,
+}
+
+impl Add for UsageTotals {
+  type Output = UsageTotals;
+
+  fn add(self, other: UsageTotals) -> UsageTotals {
+    UsageTotals {
+      bytes: self.bytes.saturating_add(other.bytes),
+      sms: self.sms.saturating_add(other.sms),
+      sessions: self.sessions.saturating_add(other.sessions),
+    }
+  }
+}
+
+impl AddAssign for UsageTotals {
+  fn add_assign(&mut self, other: UsageTotals) {
+    *self = *self + other;
+  }
+}
+
+impl From<&UsageRecord> for UsageTotals {
+  fn from(record: &UsageRecord) -> UsageTotals {
+    UsageTotals {
+      bytes: record.bytes_used,
+      sms: record.sms_used,
+      sessions: record.number_of_sessions,
+    }
+  }
+}
+
+impl<'a> Sum<&'a UsageRecord> for UsageTotals {
+  fn sum<I: Iterator<Item = &'a UsageRecord>>(records: I) -> UsageTotals {
+    records
+      .map(UsageTotals::from)
+      .fold(UsageTotals::default(), Add::add)
+  }
+}
+
+impl Sum for UsageTotals {
+  fn sum<I: Iterator<Item = UsageTotals>>(totals: I) -> UsageTotals {
+    totals.fold(UsageTotals::default(), Add::add)
+  }
+}